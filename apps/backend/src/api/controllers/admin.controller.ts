@@ -2,6 +2,7 @@ import { Request, Response, NextFunction } from 'express';
 import { UnauthorizedError, BadRequestError } from '../../utils/errors';
 import * as notificationsService from '../../services/notifications.service';
 import * as adminService from '../../services/admin.service';
+import * as escrowWebhooksService from '../../services/escrow-webhooks.service';
 import { NotificationType, DisputeStatus, ListingStatus } from '../../types';
 import logger from '../../utils/logger';
 
@@ -181,3 +182,55 @@ export const suspendUser = async (req: Request, res: Response, next: NextFunctio
     next(error);
   }
 };
+
+/**
+ * List escrow webhooks that exhausted their delivery attempts
+ */
+export const getWebhookDeadLetters = async (req: Request, res: Response, next: NextFunction) => {
+  try {
+    const deadLetters = await escrowWebhooksService.getDeadLetters();
+    
+    res.status(200).json({
+      success: true,
+      data: deadLetters
+    });
+  } catch (error) {
+    next(error);
+  }
+};
+
+/**
+ * Retry delivery of a dead-lettered escrow webhook
+ */
+export const requeueWebhookDeadLetter = async (req: Request, res: Response, next: NextFunction) => {
+  try {
+    const { id } = req.params;
+    
+    const change = await escrowWebhooksService.requeueDeadLetter(id);
+    
+    res.status(200).json({
+      success: true,
+      data: change
+    });
+  } catch (error) {
+    next(error);
+  }
+};
+
+/**
+ * Drop a dead-lettered escrow webhook so later changes for its escrow can go out
+ */
+export const discardWebhookDeadLetter = async (req: Request, res: Response, next: NextFunction) => {
+  try {
+    const { id } = req.params;
+    
+    await escrowWebhooksService.discardDeadLetter(id);
+    
+    res.status(200).json({
+      success: true,
+      data: { message: 'Dead-lettered webhook discarded' }
+    });
+  } catch (error) {
+    next(error);
+  }
+};
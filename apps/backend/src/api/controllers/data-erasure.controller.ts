@@ -0,0 +1,68 @@
+import { Request, Response, NextFunction } from 'express';
+import * as dataErasureService from '../../services/data-erasure.service';
+import { ErasureMode } from '../../services/data-erasure.service';
+
+export const getErasureMessage = async (req: Request, res: Response, next: NextFunction) => {
+  try {
+    const walletAddress = req.user!.walletAddress;
+    const mode = (req.query.mode as ErasureMode) || ErasureMode.PSEUDONYMIZE;
+    dataErasureService.assertErasureMode(mode);
+    const requestedAt = Date.now();
+    
+    res.status(200).json({
+      status: 'success',
+      data: {
+        requestedAt,
+        mode,
+        message: dataErasureService.buildErasureMessage(walletAddress, requestedAt, mode)
+      }
+    });
+  } catch (error) {
+    next(error);
+  }
+};
+
+export const requestErasure = async (req: Request, res: Response, next: NextFunction) => {
+  try {
+    const { userId, walletAddress } = req.user!;
+    const { requestedAt, signature, mode } = req.body;
+    
+    const summary = await dataErasureService.requestErasure(
+      userId,
+      walletAddress,
+      Number(requestedAt),
+      signature,
+      (mode as ErasureMode) || ErasureMode.PSEUDONYMIZE
+    );
+    
+    res.status(200).json({
+      status: 'success',
+      data: { summary }
+    });
+  } catch (error) {
+    next(error);
+  }
+};
+
+/**
+ * Erase a user's personal data on their behalf after the request has been
+ * verified through support
+ */
+export const eraseUserData = async (req: Request, res: Response, next: NextFunction) => {
+  try {
+    const { id } = req.params;
+    const { mode } = req.body;
+    
+    const summary = await dataErasureService.eraseUserData(
+      id,
+      (mode as ErasureMode) || ErasureMode.PSEUDONYMIZE
+    );
+    
+    res.status(200).json({
+      success: true,
+      data: summary
+    });
+  } catch (error) {
+    next(error);
+  }
+};
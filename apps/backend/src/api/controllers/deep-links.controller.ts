@@ -0,0 +1,41 @@
+import { Request, Response, NextFunction } from 'express';
+import { buildDeepLink, parseDeepLink } from '../../utils/deep-link';
+import { BadRequestError } from '../../utils/errors';
+
+export const parseLink = async (req: Request, res: Response, next: NextFunction) => {
+  try {
+    const uri = req.body?.uri;
+    
+    if (!uri || typeof uri !== 'string') {
+      throw new BadRequestError('Link URI is required');
+    }
+    
+    const link = parseDeepLink(uri);
+    
+    res.status(200).json({
+      status: 'success',
+      data: { link, uri: buildDeepLink(link) }
+    });
+  } catch (error) {
+    next(error);
+  }
+};
+
+export const buildLink = async (req: Request, res: Response, next: NextFunction) => {
+  try {
+    const { action, escrowAddress, amount, currency, listingId, reasonCode } = req.body || {};
+    
+    if (!action || !escrowAddress) {
+      throw new BadRequestError('Action and escrow address are required');
+    }
+    
+    const uri = buildDeepLink({ action, escrowAddress, amount, currency, listingId, reasonCode });
+    
+    res.status(200).json({
+      status: 'success',
+      data: { uri }
+    });
+  } catch (error) {
+    next(error);
+  }
+};
@@ -1,13 +1,14 @@
 import { Request, Response, NextFunction } from 'express';
 import * as disputesService from '../../services/disputes.service';
+import * as disputeReasonsService from '../../services/dispute-reasons.service';
 import { DisputeStatus } from '../../types';
 
 export async function createDispute(req: Request, res: Response, next: NextFunction) {
   try {
-    const { escrowId, reason } = req.body;
+    const { escrowId, reason, details, reasonCode } = req.body;
     const userId = req.user!.userId;
     
-    const dispute = await disputesService.createDispute(escrowId, userId, reason);
+    const dispute = await disputesService.createDispute(escrowId, userId, reason, details, reasonCode);
     
     return res.status(201).json({
       status: 'success',
@@ -18,6 +19,24 @@ export async function createDispute(req: Request, res: Response, next: NextFunct
   }
 }
 
+export async function getDisputeReasons(req: Request, res: Response, next: NextFunction) {
+  try {
+    // Repeated or nested ?locale values are ignored in favour of Accept-Language
+    const queryLocale = typeof req.query.locale === 'string' ? req.query.locale : undefined;
+    const locale = disputeReasonsService.resolveLocale(
+      queryLocale || req.acceptsLanguages(...disputeReasonsService.getSupportedLocales()) || undefined
+    );
+    const reasons = disputeReasonsService.getDisputeReasons(locale);
+    
+    return res.status(200).json({
+      status: 'success',
+      data: { locale, reasons }
+    });
+  } catch (error) {
+    next(error);
+  }
+}
+
 export async function getDispute(req: Request, res: Response, next: NextFunction) {
   try {
     const { id } = req.params;
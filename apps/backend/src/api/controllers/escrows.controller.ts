@@ -23,6 +23,26 @@ export const createEscrow = async (req: Request, res: Response, next: NextFuncti
   }
 };
 
+export const preflightEscrow = async (req: Request, res: Response, next: NextFunction) => {
+  try {
+    const buyerId = req.user!.userId;
+    const { listingId } = req.body;
+    
+    if (!listingId) {
+      throw new BadRequestError('Listing ID is required');
+    }
+    
+    const preflight = await escrowsService.preflightEscrowFunding(buyerId, listingId);
+    
+    res.status(200).json({
+      status: 'success',
+      data: { preflight }
+    });
+  } catch (error) {
+    next(error);
+  }
+};
+
 export const getEscrowById = async (req: Request, res: Response, next: NextFunction) => {
   try {
     const { id } = req.params;
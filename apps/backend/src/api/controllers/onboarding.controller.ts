@@ -0,0 +1,27 @@
+import { Request, Response, NextFunction } from 'express';
+import * as onboardingService from '../../services/onboarding.service';
+import { BadRequestError } from '../../utils/errors';
+
+export const importListings = async (req: Request, res: Response, next: NextFunction) => {
+  try {
+    const sellerId = req.user!.userId;
+    const csv = typeof req.body === 'string' ? req.body : req.body?.csv;
+    
+    if (!csv || typeof csv !== 'string') {
+      throw new BadRequestError('CSV content is required');
+    }
+    
+    const result = await onboardingService.importListingsFromCsv(sellerId, csv);
+    
+    res.status(201).json({
+      status: 'success',
+      data: {
+        imported: result.imported,
+        failed: result.failed,
+        total: result.imported.length + result.failed.length
+      }
+    });
+  } catch (error) {
+    next(error);
+  }
+};
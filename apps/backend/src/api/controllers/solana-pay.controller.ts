@@ -20,7 +20,7 @@ const createEscrowPaymentSchema = z.object({
   sellerWalletAddress: z.string().min(32).max(44),
   buyerWalletAddress: z.string().min(32).max(44),
   amount: z.number().positive(),
-  listingId: z.string(),
+  listingId: z.string().uuid(),
   currency: z.string().optional().default('USDC'),
   memo: z.string().optional()
 });
@@ -1,5 +1,6 @@
 import { Router } from 'express';
 import * as adminController from '../controllers/admin.controller';
+import * as dataErasureController from '../controllers/data-erasure.controller';
 import authenticate from '../middleware/auth';
 import { isAdmin } from '../middleware/admin.middleware';
 
@@ -21,4 +22,12 @@ router.post('/broadcast-notification', adminController.broadcastNotification);
 router.patch('/listings/:id/suspend', adminController.suspendListing);
 router.patch('/users/:id/suspend', adminController.suspendUser);
 
+// Data retention endpoints
+router.post('/users/:id/erase', dataErasureController.eraseUserData);
+
+// Escrow webhook endpoints
+router.get('/webhooks/dead-letters', adminController.getWebhookDeadLetters);
+router.post('/webhooks/dead-letters/:id/requeue', adminController.requeueWebhookDeadLetter);
+router.delete('/webhooks/dead-letters/:id', adminController.discardWebhookDeadLetter);
+
 export default router;
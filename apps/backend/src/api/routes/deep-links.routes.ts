@@ -0,0 +1,10 @@
+import { Router } from 'express';
+import * as deepLinksController from '../controllers/deep-links.controller';
+
+const router = Router();
+
+// Links carry no secrets, so apps can validate them before the user signs in
+router.post('/parse', deepLinksController.parseLink);
+router.post('/', deepLinksController.buildLink);
+
+export default router;
@@ -9,6 +9,7 @@ router.use(authenticate);
 
 // User routes
 router.post('/', disputesController.createDispute);
+router.get('/reasons', disputesController.getDisputeReasons);
 router.get('/user', disputesController.getUserDisputes);
 router.get('/:id', disputesController.getDispute);
 
@@ -7,6 +7,7 @@ const router = Router();
 router.use(authenticate);
 
 router.post('/', escrowsController.createEscrow);
+router.post('/preflight', escrowsController.preflightEscrow);
 router.get('/', escrowsController.getUserEscrows);
 router.get('/:id', escrowsController.getEscrowById);
 router.post('/:id/fund', escrowsController.fundEscrow);
@@ -11,6 +11,8 @@ import solanaPayRoutes from './solana-pay.routes';
 import reputationRoutes from './reputation.routes';
 import perenaRoutes from './perena.routes';
 import reclaimRoutes from './reclaim.routes';
+import onboardingRoutes from './onboarding.routes';
+import deepLinksRoutes from './deep-links.routes';
 
 const router = Router();
 
@@ -26,5 +28,7 @@ router.use('/solana-pay', solanaPayRoutes);
 router.use('/reputation', reputationRoutes);
 router.use('/perena', perenaRoutes);
 router.use('/reclaim', reclaimRoutes);
+router.use('/onboarding', onboardingRoutes);
+router.use('/deep-links', deepLinksRoutes);
 
 export default router;
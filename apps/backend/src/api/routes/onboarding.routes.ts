@@ -0,0 +1,16 @@
+import express, { Router } from 'express';
+import * as onboardingController from '../controllers/onboarding.controller';
+import authenticate from '../middleware/auth';
+
+const router = Router();
+
+router.use(authenticate);
+
+// Accepts either a raw text/csv body or JSON of the form { "csv": "..." }
+router.post(
+  '/listings',
+  express.text({ type: 'text/csv', limit: '10mb' }),
+  onboardingController.importListings
+);
+
+export default router;
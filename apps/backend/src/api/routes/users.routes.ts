@@ -1,5 +1,6 @@
 import { Router } from 'express';
 import * as usersController from '../controllers/users.controller';
+import * as dataErasureController from '../controllers/data-erasure.controller';
 import authenticate from '../middleware/auth';
 
 const router = Router();
@@ -10,5 +11,7 @@ router.use(authenticate);
 
 router.get('/profile', usersController.getProfile);
 router.patch('/profile', usersController.updateProfile);
+router.get('/erasure/message', dataErasureController.getErasureMessage);
+router.post('/erasure', dataErasureController.requestErasure);
 
 export default router;
@@ -0,0 +1,181 @@
+import { AccountInfo, Commitment, Connection, PublicKey } from '@solana/web3.js';
+import logger from '../utils/logger';
+
+const DEFAULT_TTL_MS = 30 * 1000;
+const DEFAULT_MAX_ENTRIES = 1000;
+
+interface CacheEntry {
+  account: AccountInfo<Buffer> | null;
+  slot: number;
+  fetchedAt: number;
+}
+
+interface CacheOptions {
+  commitment?: Commitment;
+  // How long an entry without a live subscription is trusted
+  ttlMs?: number;
+  // Least recently used entries are evicted beyond this size, and stop being watched
+  maxEntries?: number;
+}
+
+/**
+ * Caches escrow account reads keyed by address. Each entry remembers the slot it
+ * was read at, so callers that just landed a transaction can ask for a view at
+ * least as new as that slot. Watched accounts are kept current by an
+ * onAccountChange subscription and do not expire.
+ */
+export class EscrowAccountCache {
+  private connection: Connection;
+  private commitment: Commitment;
+  private ttlMs: number;
+  private maxEntries: number;
+  // Map iteration follows insertion order; entries are re-inserted on use so
+  // the first key is always the least recently used
+  private entries: Map<string, CacheEntry> = new Map();
+  private subscriptions: Map<string, number> = new Map();
+
+  constructor(connection: Connection, options: CacheOptions = {}) {
+    this.connection = connection;
+    this.commitment = options.commitment || 'confirmed';
+    this.ttlMs = options.ttlMs ?? DEFAULT_TTL_MS;
+    this.maxEntries = options.maxEntries ?? DEFAULT_MAX_ENTRIES;
+  }
+
+  get size(): number {
+    return this.entries.size;
+  }
+
+  get watchedCount(): number {
+    return this.subscriptions.size;
+  }
+
+  async get(address: PublicKey, minSlot = 0): Promise<AccountInfo<Buffer> | null> {
+    const key = address.toString();
+    const entry = this.entries.get(key);
+
+    if (entry && !this.isFresh(key, entry)) {
+      this.entries.delete(key);
+    } else if (entry && entry.slot >= minSlot) {
+      this.entries.delete(key);
+      this.entries.set(key, entry);
+      return entry.account;
+    }
+
+    return this.refresh(address, minSlot);
+  }
+
+  async refresh(address: PublicKey, minSlot = 0): Promise<AccountInfo<Buffer> | null> {
+    const { context, value } = await this.connection.getAccountInfoAndContext(address, {
+      commitment: this.commitment,
+      minContextSlot: minSlot || undefined
+    });
+
+    this.store(address.toString(), value, context.slot);
+    return value;
+  }
+
+  // Write-through after our own transaction: anything older than the slot the
+  // transaction landed in is refetched
+  async recordTransaction(address: PublicKey, slot: number): Promise<void> {
+    const key = address.toString();
+    const entry = this.entries.get(key);
+
+    if (entry && entry.slot >= slot && this.isFresh(key, entry)) {
+      return;
+    }
+
+    try {
+      await this.refresh(address, slot);
+    } catch (error) {
+      logger.warn(`Failed to refresh cached escrow account ${key}:`, error);
+      this.entries.delete(key);
+    }
+  }
+
+  // Keep the account current from change notifications instead of refetching it
+  // once the ttl runs out
+  async watch(address: PublicKey): Promise<void> {
+    const key = address.toString();
+
+    if (this.subscriptions.has(key)) {
+      return;
+    }
+
+    const subscriptionId = this.connection.onAccountChange(
+      address,
+      (account, context) => {
+        if (this.subscriptions.has(key)) {
+          this.store(key, account, context.slot);
+        }
+      },
+      this.commitment
+    );
+    this.subscriptions.set(key, subscriptionId);
+
+    // Notifications only cover later changes, so start from a fresh read
+    try {
+      await this.refresh(address);
+    } catch (error) {
+      await this.unwatch(address);
+      throw error;
+    }
+  }
+
+  async unwatch(address: PublicKey): Promise<void> {
+    const key = address.toString();
+    const subscriptionId = this.subscriptions.get(key);
+
+    if (subscriptionId === undefined) {
+      return;
+    }
+
+    this.subscriptions.delete(key);
+    // Without the subscription the entry can no longer be trusted past the ttl
+    this.entries.delete(key);
+
+    try {
+      await this.connection.removeAccountChangeListener(subscriptionId);
+    } catch (error) {
+      logger.warn(`Failed to remove account subscription for ${key}:`, error);
+    }
+  }
+
+  isWatched(address: PublicKey): boolean {
+    return this.subscriptions.has(address.toString());
+  }
+
+  invalidate(address: PublicKey): void {
+    this.entries.delete(address.toString());
+  }
+
+  async clear(): Promise<void> {
+    const watched = Array.from(this.subscriptions.keys());
+    await Promise.all(watched.map((key) => this.unwatch(new PublicKey(key))));
+    this.entries.clear();
+  }
+
+  private store(key: string, account: AccountInfo<Buffer> | null, slot: number): void {
+    const entry = this.entries.get(key);
+
+    // Change notifications and fetches can arrive out of order
+    if (entry && entry.slot > slot && this.isFresh(key, entry)) {
+      return;
+    }
+
+    this.entries.delete(key);
+    this.entries.set(key, { account, slot, fetchedAt: Date.now() });
+
+    while (this.entries.size > this.maxEntries) {
+      const oldest = this.entries.keys().next().value as string;
+      this.entries.delete(oldest);
+
+      if (this.subscriptions.has(oldest)) {
+        this.unwatch(new PublicKey(oldest)).catch(() => undefined);
+      }
+    }
+  }
+
+  private isFresh(key: string, entry: CacheEntry): boolean {
+    return this.subscriptions.has(key) || Date.now() - entry.fetchedAt < this.ttlMs;
+  }
+}
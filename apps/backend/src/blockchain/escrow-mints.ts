@@ -0,0 +1,25 @@
+// SPL mints the escrow program can hold, per network
+export const TOKEN_MINT_ADDRESSES: {[network: string]: {[currency: string]: string}} = {
+  mainnet: {
+    'USDC': 'EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v',
+    'USDT': 'Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB',
+    'PAX': 'BbBCH5yTRd2jcZEr2PAYYb7BoNFTYenNkFEeJoaJRvAn'
+  },
+  devnet: {
+    'USDC': '4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU',
+    'USDT': 'BQcdHdAQW1hczDbBi9hiegXAR7A98Q9jx3X3sXJHgS7b',
+    'PAX': 'DJafV9qemGp7mLMEn5wrfqaFwxsbLgUsGVA16K9PmCnj'
+  }
+};
+
+// Anything other than mainnet uses the devnet mints
+export const ESCROW_NETWORK = (process.env.SOLANA_NETWORK === 'mainnet') ? 'mainnet' : 'devnet';
+
+// Currencies an escrow can be funded in on the configured network
+export const ESCROW_CURRENCIES: string[] = Object.keys(TOKEN_MINT_ADDRESSES[ESCROW_NETWORK]);
+
+export function getEscrowMintAddress(currency: string): string | undefined {
+  return Object.prototype.hasOwnProperty.call(TOKEN_MINT_ADDRESSES[ESCROW_NETWORK], currency)
+    ? TOKEN_MINT_ADDRESSES[ESCROW_NETWORK][currency]
+    : undefined;
+}
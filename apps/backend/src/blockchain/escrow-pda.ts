@@ -0,0 +1,35 @@
+import { PublicKey } from '@solana/web3.js';
+
+export const ESCROW_PROGRAM_ID = new PublicKey('EscDoNyGa2G2JbCt525KJSsBi6phRUMqtJWWYwfriKTT');
+const ESCROW_SEED_PREFIX = 'escrow';
+
+// Escrow PDA seeds are limited to 32 bytes, so the dashes are dropped from the listing UUID
+export function toEscrowListingSeed(listingId: string): string {
+  return listingId.replace(/-/g, '');
+}
+
+/**
+ * The single derivation of an escrow PDA from its parties and listing. Every
+ * path that hands out an escrow address must go through here so the address a
+ * buyer is asked to fund is the one the program initializes.
+ *
+ * There is no per-escrow nonce in the seeds, so a buyer gets exactly one
+ * escrow per listing: buying the same listing again resolves to the account
+ * that already exists. Listing ids must be UUIDs to fit the seed.
+ */
+export async function findEscrowPDA(
+  seller: PublicKey,
+  buyer: PublicKey,
+  listingId: string,
+  programId: PublicKey = ESCROW_PROGRAM_ID
+): Promise<[PublicKey, number]> {
+  return PublicKey.findProgramAddress(
+    [
+      Buffer.from(ESCROW_SEED_PREFIX),
+      seller.toBuffer(),
+      buyer.toBuffer(),
+      Buffer.from(toEscrowListingSeed(listingId))
+    ],
+    programId
+  );
+}
@@ -14,38 +14,29 @@ import {
   getAssociatedTokenAddress,
   getAccount,
   createAssociatedTokenAccountInstruction,
+  ACCOUNT_SIZE,
+  TokenAccountNotFoundError,
+  unpackAccount,
   createTransferInstruction
 } from '@solana/spl-token';
 import * as borsh from 'borsh';
 import tweetnacl from 'tweetnacl';
 import bs58 from 'bs58';
 import stablecoinService, { StablecoinType } from '../services/stablecoin.service';
-import { BlockchainError } from '../utils/errors';
+import { BadRequestError, BlockchainError } from '../utils/errors';
 import logger from '../utils/logger';
 import transactionMonitorService from '../services/transaction-monitor.service';
+import { EscrowAccountCache } from './escrow-account-cache';
+import { ESCROW_PROGRAM_ID, findEscrowPDA, toEscrowListingSeed } from './escrow-pda';
+import { getEscrowMintAddress } from './escrow-mints';
 
 const PLATFORM_FEE_PERCENTAGE = Number(process.env.PLATFORM_FEE_PERCENTAGE || '2.5');
 const PLATFORM_WALLET_ADDRESS = process.env.PLATFORM_WALLET_ADDRESS;
-const ESCROW_PROGRAM_ID = new PublicKey('EscDoNyGa2G2JbCt525KJSsBi6phRUMqtJWWYwfriKTT'); 
-const NETWORK = process.env.SOLANA_NETWORK || 'devnet';
 
 const DAY_IN_MS = 24 * 60 * 60 * 1000;
 const DEFAULT_ESCROW_DURATION_DAYS = 7;
 const DISPUTE_WINDOW_DAYS = 3;
-const ESCROW_SEED_PREFIX = 'escrow';
-
-const TOKEN_MINT_ADDRESSES: {[network: string]: {[currency: string]: string}} = {
-  mainnet: {
-    'USDC': 'EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v',
-    'USDT': 'Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB',
-    'PAX': 'BbBCH5yTRd2jcZEr2PAYYb7BoNFTYenNkFEeJoaJRvAn'
-  },
-  devnet: {
-    'USDC': '4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU',
-    'USDT': 'BQcdHdAQW1hczDbBi9hiegXAR7A98Q9jx3X3sXJHgS7b',
-    'PAX': 'DJafV9qemGp7mLMEn5wrfqaFwxsbLgUsGVA16K9PmCnj'
-  }
-};
+const LAMPORTS_PER_SIGNATURE = 5000;
 
 enum EscrowState {
   Uninitialized,
@@ -180,9 +171,33 @@ interface TransactionResult {
   status: string;
 }
 
+export enum FundingPrerequisite {
+  LISTING_AVAILABLE = 'listing_available',
+  BUYER_IS_NOT_SELLER = 'buyer_is_not_seller',
+  BUYER_TOKEN_ACCOUNT = 'buyer_token_account',
+  BUYER_TOKEN_BALANCE = 'buyer_token_balance',
+  BUYER_SOL_BALANCE = 'buyer_sol_balance',
+  SELLER_TOKEN_ACCOUNT = 'seller_token_account'
+}
+
+export interface FundingPrerequisiteCheck {
+  prerequisite: FundingPrerequisite;
+  satisfied: boolean;
+  message: string;
+  required?: string;
+  available?: string;
+}
+
+export interface FundingPreflightResult {
+  ready: boolean;
+  checks: FundingPrerequisiteCheck[];
+  unmet: FundingPrerequisiteCheck[];
+}
+
 export class EscrowService {
   private connection: Connection;
   private programId: PublicKey;
+  private accountCache: EscrowAccountCache;
 
   constructor() {
     // Connect to the desired network (devnet/mainnet)
@@ -191,19 +206,12 @@ export class EscrowService {
       'confirmed'
     );
     this.programId = ESCROW_PROGRAM_ID;
+    this.accountCache = new EscrowAccountCache(this.connection);
   }
 
   // Find the Escrow PDA (Program Derived Address)
   async findEscrowPDA(seller: PublicKey, buyer: PublicKey, listingId: string): Promise<[PublicKey, number]> {
-    return PublicKey.findProgramAddress(
-      [
-        Buffer.from(ESCROW_SEED_PREFIX),
-        seller.toBuffer(),
-        buyer.toBuffer(),
-        Buffer.from(listingId)
-      ],
-      this.programId
-    );
+    return findEscrowPDA(seller, buyer, listingId, this.programId);
   }
 
   // Creates an escrow account on-chain
@@ -211,6 +219,7 @@ export class EscrowService {
     sellerWalletAddress: string,
     buyerWalletAddress: string,
     amount: number,
+    listingId: string,
     currency = 'USDC',
     durationDays = DEFAULT_ESCROW_DURATION_DAYS
   ): Promise<{ escrowAddress: string; releaseTime: Date }> {
@@ -221,6 +230,10 @@ export class EscrowService {
         throw new Error('Buyer and seller wallet addresses are required');
       }
       
+      if (!listingId) {
+        throw new Error('Listing ID is required');
+      }
+      
       if (amount <= 0) {
         throw new Error('Amount must be greater than 0');
       }
@@ -228,8 +241,6 @@ export class EscrowService {
       const buyerPubkey = new PublicKey(buyerWalletAddress);
       const sellerPubkey = new PublicKey(sellerWalletAddress);
 
-      const listingId = Date.now().toString() + Math.random().toString().substring(2, 10);
-
       const releaseTime = new Date(Date.now() + durationDays * DAY_IN_MS);
       const releaseTimestamp = Math.floor(releaseTime.getTime() / 1000);
       const disputeTimeWindow = DISPUTE_WINDOW_DAYS * 24 * 60 * 60; // In seconds
@@ -269,7 +280,8 @@ export class EscrowService {
         amount: amount,
         releaseTimestamp: releaseTimestamp,
         disputeTimeWindow: DISPUTE_WINDOW_DAYS * 24 * 60 * 60, // 3 days in seconds
-        listingId: listingId
+        // Must match the seed the PDA was derived from
+        listingId: toEscrowListingSeed(listingId)
       });
       
       // Serialize the instruction data
@@ -297,7 +309,8 @@ export class EscrowService {
         [signerKeypair]
       );
       
-      await this.connection.confirmTransaction(signature, 'confirmed');
+      const confirmation = await this.connection.confirmTransaction(signature, 'confirmed');
+      await this.accountCache.recordTransaction(escrowPubkey, confirmation.context.slot);
       
       return { 
         transactionId: signature 
@@ -401,9 +414,8 @@ export class EscrowService {
       // Check if escrow token account exists, if not create it
       let transaction = new Transaction();
       
-      try {
-        await getAccount(this.connection, escrowTokenAccount);
-      } catch (error) {
+      const escrowVault = await this.getEscrowVaultOrNull(escrowTokenAccount);
+      if (!escrowVault) {
         // Account doesn't exist, create it
         transaction.add(
           createAssociatedTokenAccountInstruction(
@@ -453,7 +465,9 @@ export class EscrowService {
         [buyerKeypair]
       );
       
-      await this.connection.confirmTransaction(signature, 'confirmed');
+      const confirmation = await this.connection.confirmTransaction(signature, 'confirmed');
+      await this.accountCache.recordTransaction(escrowPubkey, confirmation.context.slot);
+      await this.accountCache.recordTransaction(escrowTokenAccount, confirmation.context.slot);
       
       return signature;
     } catch (error: any) {
@@ -524,7 +538,8 @@ export class EscrowService {
         [adminKeypair]
       );
       
-      await this.connection.confirmTransaction(signature, 'confirmed');
+      const confirmation = await this.connection.confirmTransaction(signature, 'confirmed');
+      await this.accountCache.recordTransaction(escrowPubkey, confirmation.context.slot);
       
       return {
         transactionId: signature,
@@ -629,7 +644,8 @@ export class EscrowService {
         [adminKeypair]
       );
       
-      await this.connection.confirmTransaction(signature, 'confirmed');
+      const confirmation = await this.connection.confirmTransaction(signature, 'confirmed');
+      await this.accountCache.recordTransaction(escrowPubkey, confirmation.context.slot);
       
       return {
         transactionId: signature,
@@ -672,6 +688,137 @@ export class EscrowService {
     return transaction;
   }
 
+  // Check everything a buyer needs before a funding transaction is built, so the UI
+  // can walk them through what is missing instead of surfacing a failed transaction
+  async preflightFunding(
+    buyerWalletAddress: string,
+    sellerWalletAddress: string,
+    amount: number,
+    currency = 'USDC',
+    listingId?: string
+  ): Promise<FundingPreflightResult> {
+    // Listings may be priced in currencies the escrow cannot hold, e.g. SOL
+    let mint: string;
+    try {
+      mint = this.getTokenMintAddress(currency);
+    } catch (error) {
+      throw new BadRequestError(`Escrow funding is not supported for currency: ${currency}`);
+    }
+
+    try {
+      const buyerPubkey = new PublicKey(buyerWalletAddress);
+      const sellerPubkey = new PublicKey(sellerWalletAddress);
+      const mintAddress = new PublicKey(mint);
+
+      const buyerTokenAccount = await getAssociatedTokenAddress(mintAddress, buyerPubkey);
+      const sellerTokenAccount = await getAssociatedTokenAddress(mintAddress, sellerPubkey);
+
+      // Same amount fundEscrow transfers; the platform fee comes out of it
+      const requiredTokens = this.convertToTokenAmount(amount);
+
+      const checks: FundingPrerequisiteCheck[] = [];
+
+      const buyerAccount = await this.getTokenAccountOrNull(buyerTokenAccount);
+      checks.push({
+        prerequisite: FundingPrerequisite.BUYER_TOKEN_ACCOUNT,
+        satisfied: buyerAccount !== null,
+        message: buyerAccount
+          ? `Buyer has a ${currency} token account`
+          : `Buyer needs a ${currency} token account before funding`
+      });
+
+      const availableTokens = buyerAccount ? buyerAccount.amount : BigInt(0);
+      checks.push({
+        prerequisite: FundingPrerequisite.BUYER_TOKEN_BALANCE,
+        satisfied: availableTokens >= requiredTokens,
+        message: availableTokens >= requiredTokens
+          ? `Buyer balance covers ${amount} ${currency}`
+          : `Buyer needs ${amount} ${currency} to fund the escrow`,
+        required: requiredTokens.toString(),
+        available: availableTokens.toString()
+      });
+
+      // The buyer pays the network fee and, if the vault does not exist yet, its rent.
+      // Without a listing the escrow cannot be derived, so the vault is assumed missing
+      let escrowVault = null;
+      if (listingId) {
+        const [escrowPDA] = await this.findEscrowPDA(sellerPubkey, buyerPubkey, listingId);
+        escrowVault = await this.getEscrowVaultOrNull(
+          await getAssociatedTokenAddress(mintAddress, escrowPDA, true)
+        );
+      }
+      let requiredLamports = LAMPORTS_PER_SIGNATURE;
+      if (!escrowVault) {
+        requiredLamports += await this.connection.getMinimumBalanceForRentExemption(ACCOUNT_SIZE);
+      }
+      const availableLamports = await this.connection.getBalance(buyerPubkey);
+      checks.push({
+        prerequisite: FundingPrerequisite.BUYER_SOL_BALANCE,
+        satisfied: availableLamports >= requiredLamports,
+        message: availableLamports >= requiredLamports
+          ? 'Buyer has enough SOL for network fees'
+          : `Buyer needs at least ${requiredLamports / LAMPORTS_PER_SOL} SOL for network fees and rent`,
+        required: requiredLamports.toString(),
+        available: availableLamports.toString()
+      });
+
+      const sellerAccount = await this.getTokenAccountOrNull(sellerTokenAccount);
+      checks.push({
+        prerequisite: FundingPrerequisite.SELLER_TOKEN_ACCOUNT,
+        satisfied: sellerAccount !== null,
+        message: sellerAccount
+          ? `Seller has a ${currency} token account`
+          : `Seller needs a ${currency} token account to receive the payout`
+      });
+
+      const unmet = checks.filter((check) => !check.satisfied);
+
+      return {
+        ready: unmet.length === 0,
+        checks,
+        unmet
+      };
+    } catch (error: any) {
+      logger.error('Error running funding preflight:', error);
+      throw new BlockchainError(`Failed to run funding preflight: ${error.message}`);
+    }
+  }
+
+  // Keep an escrow and its vault current in the account cache while its funding
+  // is pending, so repeated reads are served from change notifications
+  async watchEscrow(escrowAddress: string, currency = 'USDC'): Promise<void> {
+    const escrowPubkey = new PublicKey(escrowAddress);
+    const mintAddress = new PublicKey(this.getTokenMintAddress(currency));
+    const vault = await getAssociatedTokenAddress(mintAddress, escrowPubkey, true);
+
+    await Promise.all([this.accountCache.watch(escrowPubkey), this.accountCache.watch(vault)]);
+  }
+
+  async unwatchEscrow(escrowAddress: string, currency = 'USDC'): Promise<void> {
+    const escrowPubkey = new PublicKey(escrowAddress);
+    const mintAddress = new PublicKey(this.getTokenMintAddress(currency));
+    const vault = await getAssociatedTokenAddress(mintAddress, escrowPubkey, true);
+
+    await Promise.all([this.accountCache.unwatch(escrowPubkey), this.accountCache.unwatch(vault)]);
+  }
+
+  // Vaults are escrow-owned, so their reads go through the account cache
+  private async getEscrowVaultOrNull(address: PublicKey) {
+    const accountInfo = await this.accountCache.get(address);
+    return accountInfo ? unpackAccount(address, accountInfo) : null;
+  }
+
+  private async getTokenAccountOrNull(address: PublicKey) {
+    try {
+      return await getAccount(this.connection, address);
+    } catch (error) {
+      if (error instanceof TokenAccountNotFoundError) {
+        return null;
+      }
+      throw error;
+    }
+  }
+
   // Verify transaction on Solana blockchain
   async verifyTransaction(signature: string): Promise<{ confirmed: boolean; status: string }> {
     try {
@@ -694,11 +841,11 @@ export class EscrowService {
   }
 
   getTokenMintAddress(currency: string): string {
-    const network = (NETWORK === 'mainnet') ? 'mainnet' : 'devnet';
-    if (!TOKEN_MINT_ADDRESSES[network][currency]) {
+    const mintAddress = getEscrowMintAddress(currency);
+    if (!mintAddress) {
       throw new Error(`Unsupported currency: ${currency}`);
     }
-    return TOKEN_MINT_ADDRESSES[network][currency];
+    return mintAddress;
   }
 
   // Convert USD amount to token amount with proper decimals
@@ -733,7 +880,7 @@ export class EscrowService {
       const escrowPubkey = new PublicKey(escrowAddress);
       
       // Get the escrow account data
-      const escrowAccountInfo = await this.connection.getAccountInfo(escrowPubkey);
+      const escrowAccountInfo = await this.accountCache.get(escrowPubkey);
       
       if (!escrowAccountInfo) {
         logger.error(`Escrow account ${escrowAddress} not found`);
@@ -782,7 +929,8 @@ export class EscrowService {
         [adminKeypair]
       );
       
-      await this.connection.confirmTransaction(signature, 'confirmed');
+      const confirmation = await this.connection.confirmTransaction(signature, 'confirmed');
+      await this.accountCache.recordTransaction(escrowPubkey, confirmation.context.slot);
       
       return true;
     } catch (error: any) {
@@ -56,6 +56,15 @@ export const config = {
   reclaim: {
     apiKey: process.env.RECLAIM_API_KEY || '' as string,
     apiUrl: process.env.RECLAIM_API_URL || 'https://api.reclaimprotocol.org/v1' as string,
+  },
+  webhooks: {
+    // Comma-separated list of endpoints notified on escrow state changes
+    urls: (process.env.ESCROW_WEBHOOK_URLS || '').split(',').map((url) => url.trim()).filter(Boolean),
+    secret: process.env.ESCROW_WEBHOOK_SECRET || '' as string,
+    pollIntervalMs: parseInt(process.env.ESCROW_WEBHOOK_POLL_INTERVAL_MS || '5000', 10) as number,
+    maxAttempts: parseInt(process.env.ESCROW_WEBHOOK_MAX_ATTEMPTS || '10', 10) as number,
+    // Delivered changes older than this are pruned from the outbox
+    retentionDays: parseInt(process.env.ESCROW_WEBHOOK_RETENTION_DAYS || '7', 10) as number,
   }
 };
 
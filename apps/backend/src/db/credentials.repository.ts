@@ -1,4 +1,4 @@
-import { query } from './index';
+import { query, Queryable } from './index';
 import { v4 as uuidv4 } from 'uuid';
 import { ReclaimCredential } from '../services/reclaim.service';
 import logger from '../utils/logger';
@@ -158,6 +158,22 @@ export async function revokeByUserId(userId: string): Promise<void> {
   }
 }
 
+export async function deleteByUserId(userId: string, db: Queryable = { query }): Promise<number> {
+  try {
+    await ensureTableExists();
+    
+    const result = await db.query(
+      `DELETE FROM reclaim_credentials WHERE user_id = $1 RETURNING id`,
+      [userId]
+    );
+    
+    return result.rows.length;
+  } catch (error) {
+    logger.error('Error deleting credentials by user ID:', error);
+    throw error;
+  }
+}
+
 async function ensureTableExists(): Promise<void> {
   try {
     await query(`
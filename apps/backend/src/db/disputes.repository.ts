@@ -1,13 +1,14 @@
 import { v4 as uuidv4 } from 'uuid';
-import { query } from './index';
-import { Dispute, DisputeStatus } from '../types';
+import { query, Queryable } from './index';
+import { Dispute, DisputeReasonCode, DisputeStatus } from '../types';
 import { NotFoundError } from '../utils/errors';
 
 export async function create(
   escrowId: string,
   initiatorId: string,
   reason: string,
-  details?: string
+  details?: string,
+  reasonCode?: DisputeReasonCode
 ): Promise<Dispute> {
   const escrowResult = await query('SELECT * FROM escrows WHERE id = $1', [escrowId]);
   if (escrowResult.rows.length === 0) {
@@ -34,6 +35,7 @@ export async function create(
     initiatorId,
     respondentId,
     reason,
+    reasonCode,
     details: details || undefined,
     status: DisputeStatus.OPEN,
     createdAt: now,
@@ -41,8 +43,8 @@ export async function create(
   };
 
   const result = await query(
-    `INSERT INTO disputes (id, escrow_id, initiator_id, respondent_id, reason, details, status, created_at, updated_at)
-     VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+    `INSERT INTO disputes (id, escrow_id, initiator_id, respondent_id, reason, reason_code, details, status, created_at, updated_at)
+     VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
      RETURNING *`,
     [
       dispute.id,
@@ -50,6 +52,7 @@ export async function create(
       dispute.initiatorId,
       dispute.respondentId,
       dispute.reason,
+      dispute.reasonCode || null,
       dispute.details,
       dispute.status,
       dispute.createdAt,
@@ -150,6 +153,26 @@ export async function findAll(
   };
 }
 
+// Only settled disputes are cleared so arbitrators keep the grounds of live ones.
+// reason is NOT NULL, so it is overwritten with a placeholder; reason_code is kept
+export async function clearDetailsByInitiator(
+  initiatorId: string,
+  reasonPlaceholder: string,
+  db: Queryable = { query }
+): Promise<number> {
+  const result = await db.query(
+    `UPDATE disputes 
+     SET details = NULL, reason = $2, updated_at = NOW()
+     WHERE initiator_id = $1
+       AND status NOT IN ('open', 'in_review')
+       AND (details IS NOT NULL OR reason <> $2)
+     RETURNING id`,
+    [initiatorId, reasonPlaceholder]
+  );
+  
+  return result.rows.length;
+}
+
 function mapRowToDispute(row: any): Dispute {
   return {
     id: row.id,
@@ -157,6 +180,7 @@ function mapRowToDispute(row: any): Dispute {
     initiatorId: row.initiator_id,
     respondentId: row.respondent_id || '', 
     reason: row.reason,
+    reasonCode: row.reason_code || undefined,
     details: row.details,
     status: row.status as DisputeStatus,
     resolution: row.resolution,
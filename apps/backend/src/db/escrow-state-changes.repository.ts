@@ -0,0 +1,164 @@
+import { query } from './index';
+import { EscrowStatus } from '../types';
+
+// Rows are written by the escrows status trigger in schema.sql
+export interface EscrowStateChange {
+  id: string;
+  escrowId: string;
+  escrowAddress: string | null;
+  listingId: string | null;
+  oldStatus: EscrowStatus | null;
+  newStatus: EscrowStatus;
+  amount: number;
+  currency: string;
+  attempts: number;
+  deliveredUrls: string[];
+  lastError: string | null;
+  deliveredAt: Date | null;
+  deadLetteredAt: Date | null;
+  createdAt: Date;
+}
+
+/**
+ * Lease the oldest undelivered change of each escrow. A change is only
+ * eligible when no earlier change for the same escrow is still undelivered,
+ * so a dead-lettered or in-flight change holds back everything after it.
+ * SKIP LOCKED plus the lease keep concurrent backend instances from claiming
+ * the same rows.
+ */
+export const claimPending = async (leaseMs: number, limit: number = 20): Promise<EscrowStateChange[]> => {
+  const result = await query(
+    `UPDATE escrow_state_changes
+     SET locked_until = NOW() + ($1 * INTERVAL '1 millisecond')
+     WHERE id IN (
+       SELECT c.id FROM escrow_state_changes c
+       WHERE c.delivered_at IS NULL
+       AND c.dead_lettered_at IS NULL
+       AND (c.locked_until IS NULL OR c.locked_until < NOW())
+       AND NOT EXISTS (
+         SELECT 1 FROM escrow_state_changes earlier
+         WHERE earlier.escrow_id = c.escrow_id
+         AND earlier.delivered_at IS NULL
+         AND earlier.id < c.id
+       )
+       ORDER BY c.id ASC
+       LIMIT $2
+       FOR UPDATE SKIP LOCKED
+     )
+     RETURNING *`,
+    [leaseMs, limit]
+  );
+  
+  return result.rows
+    .map(mapDbStateChange)
+    .sort((a: EscrowStateChange, b: EscrowStateChange) => Number(a.id) - Number(b.id));
+};
+
+export const markDelivered = async (id: string, deliveredUrls: string[]): Promise<void> => {
+  await query(
+    `UPDATE escrow_state_changes
+     SET delivered_at = NOW(),
+         delivered_urls = $2,
+         attempts = attempts + 1,
+         last_error = NULL,
+         locked_until = NULL
+     WHERE id = $1`,
+    [id, deliveredUrls]
+  );
+};
+
+export const recordFailedAttempt = async (
+  id: string,
+  error: string,
+  deliveredUrls: string[],
+  deadLetter: boolean
+): Promise<void> => {
+  await query(
+    `UPDATE escrow_state_changes
+     SET attempts = attempts + 1,
+         delivered_urls = $3,
+         last_error = $2,
+         locked_until = NULL,
+         dead_lettered_at = CASE WHEN $4 THEN NOW() ELSE NULL END
+     WHERE id = $1`,
+    [id, error, deliveredUrls, deadLetter]
+  );
+};
+
+export const findDeadLetters = async (limit: number = 100): Promise<EscrowStateChange[]> => {
+  const result = await query(
+    `SELECT * FROM escrow_state_changes
+     WHERE dead_lettered_at IS NOT NULL
+     ORDER BY id ASC
+     LIMIT $1`,
+    [limit]
+  );
+  
+  return result.rows.map(mapDbStateChange);
+};
+
+// Give a dead-lettered change a fresh set of attempts
+export const requeueDeadLetter = async (id: string): Promise<EscrowStateChange | null> => {
+  const result = await query(
+    `UPDATE escrow_state_changes
+     SET attempts = 0,
+         dead_lettered_at = NULL,
+         last_error = NULL,
+         locked_until = NULL
+     WHERE id = $1
+     AND dead_lettered_at IS NOT NULL
+     RETURNING *`,
+    [id]
+  );
+  
+  return result.rows.length > 0 ? mapDbStateChange(result.rows[0]) : null;
+};
+
+// Dropping a dead letter releases the changes queued behind it
+export const deleteDeadLetter = async (id: string): Promise<boolean> => {
+  const result = await query(
+    `DELETE FROM escrow_state_changes
+     WHERE id = $1
+     AND dead_lettered_at IS NOT NULL
+     RETURNING id`,
+    [id]
+  );
+  
+  return result.rows.length > 0;
+};
+
+export const pruneDelivered = async (retentionDays: number): Promise<number> => {
+  const result = await query(
+    `DELETE FROM escrow_state_changes
+     WHERE delivered_at IS NOT NULL
+     AND delivered_at < NOW() - ($1 * INTERVAL '1 day')`,
+    [retentionDays]
+  );
+  
+  return result.rowCount || 0;
+};
+
+export const enableRecording = async (): Promise<void> => {
+  await query(
+    `UPDATE escrow_webhook_settings SET recording_enabled = TRUE WHERE NOT recording_enabled`
+  );
+};
+
+const mapDbStateChange = (row: any): EscrowStateChange => {
+  return {
+    id: String(row.id),
+    escrowId: row.escrow_id,
+    escrowAddress: row.escrow_address,
+    listingId: row.listing_id,
+    oldStatus: row.old_status,
+    newStatus: row.new_status,
+    amount: parseFloat(row.amount),
+    currency: row.currency,
+    attempts: row.attempts,
+    deliveredUrls: row.delivered_urls || [],
+    lastError: row.last_error,
+    deliveredAt: row.delivered_at,
+    deadLetteredAt: row.dead_lettered_at,
+    createdAt: row.created_at
+  };
+};
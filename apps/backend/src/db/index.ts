@@ -66,8 +66,30 @@ export const getClient = async () => {
   return clientWithTracking;
 };
 
+// Anything repositories can run a statement on: the pool-backed query above or
+// a client checked out for a transaction
+export interface Queryable {
+  query: (text: string, params?: any[]) => Promise<any>;
+}
+
+export const withTransaction = async <T>(work: (client: Queryable) => Promise<T>): Promise<T> => {
+  const client = await getClient();
+  try {
+    await client.query('BEGIN');
+    const result = await work(client);
+    await client.query('COMMIT');
+    return result;
+  } catch (error) {
+    await client.query('ROLLBACK');
+    throw error;
+  } finally {
+    client.release();
+  }
+};
+
 export default {
   query,
   getClient,
+  withTransaction,
   pool,
 };
@@ -1,4 +1,4 @@
-import { query } from './index';
+import { query, Queryable } from './index';
 import { Notification, NotificationType } from '../types';
 import { v4 as uuidv4 } from 'uuid';
 
@@ -99,3 +99,37 @@ export async function deleteNotification(id: string, userId: string): Promise<bo
 
   return result.rows.length > 0;
 }
+
+export async function deleteAllNotificationsForUser(userId: string, db: Queryable = { query }): Promise<number> {
+  const result = await db.query(
+    `DELETE FROM notifications
+     WHERE user_id = $1
+     RETURNING id`,
+    [userId]
+  );
+
+  return result.rows.length;
+}
+
+// Notifications sent to a user's escrow counterparties can quote their username
+export async function replaceTextForCounterparties(
+  userId: string,
+  text: string,
+  replacement: string,
+  db: Queryable = { query }
+): Promise<number> {
+  const result = await db.query(
+    `UPDATE notifications
+     SET message = REPLACE(message, $2, $3)
+     WHERE STRPOS(message, $2) > 0
+       AND user_id IN (
+         SELECT seller_id FROM escrows WHERE buyer_id = $1
+         UNION
+         SELECT buyer_id FROM escrows WHERE seller_id = $1
+       )
+     RETURNING id`,
+    [userId, text, replacement]
+  );
+
+  return result.rows.length;
+}
@@ -1,4 +1,4 @@
-import { query } from './index';
+import { query, Queryable } from './index';
 import { Review } from '../types';
 
 /**
@@ -20,6 +20,21 @@ export const create = async (
   return mapDbReviewToReview(result.rows[0]);
 };
 
+/**
+ * Remove the free-text comments a user has written, keeping the ratings
+ */
+export const clearCommentsByReviewer = async (reviewerId: string, db: Queryable = { query }): Promise<number> => {
+  const result = await db.query(
+    `UPDATE reviews 
+     SET comment = NULL 
+     WHERE reviewer_id = $1 AND comment IS NOT NULL 
+     RETURNING id`,
+    [reviewerId]
+  );
+
+  return result.rows.length;
+};
+
 /**
  * Get reviews for a specific user (as reviewee)
  */
@@ -64,6 +64,8 @@ CREATE TABLE IF NOT EXISTS disputes (
   escrow_id UUID NOT NULL REFERENCES escrows(id) ON DELETE CASCADE,
   initiator_id UUID NOT NULL REFERENCES users(id),
   reason TEXT NOT NULL,
+  -- Catalog code the reason was picked from, if any (see dispute-reasons.service)
+  reason_code VARCHAR(50),
   status VARCHAR(50) NOT NULL DEFAULT 'open',
   resolution TEXT,
   resolved_at TIMESTAMP WITH TIME ZONE,
@@ -71,6 +73,8 @@ CREATE TABLE IF NOT EXISTS disputes (
   updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
 );
 
+ALTER TABLE disputes ADD COLUMN IF NOT EXISTS reason_code VARCHAR(50);
+
 CREATE TABLE IF NOT EXISTS notifications (
   id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
   user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
@@ -80,6 +84,66 @@ CREATE TABLE IF NOT EXISTS notifications (
   created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
 );
 
+-- Outbox of escrow status transitions, drained by the webhook daemon
+CREATE TABLE IF NOT EXISTS escrow_state_changes (
+  id BIGSERIAL PRIMARY KEY,
+  escrow_id UUID NOT NULL REFERENCES escrows(id) ON DELETE CASCADE,
+  escrow_address VARCHAR(255),
+  listing_id UUID,
+  old_status VARCHAR(50),
+  new_status VARCHAR(50) NOT NULL,
+  amount DECIMAL NOT NULL,
+  currency VARCHAR(50) NOT NULL,
+  attempts INTEGER NOT NULL DEFAULT 0,
+  -- Endpoints that already accepted this change, skipped on retry
+  delivered_urls TEXT[] NOT NULL DEFAULT '{}',
+  last_error TEXT,
+  -- Lease held by the backend instance currently delivering this change
+  locked_until TIMESTAMP WITH TIME ZONE,
+  delivered_at TIMESTAMP WITH TIME ZONE,
+  -- Set once a change has used up its delivery attempts
+  dead_lettered_at TIMESTAMP WITH TIME ZONE,
+  created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
+);
+
+-- Single row switched on by the first webhook daemon to start, so nothing is
+-- recorded until webhooks are configured. Instances never switch it off; to stop
+-- recording after removing webhooks everywhere, set recording_enabled = FALSE
+CREATE TABLE IF NOT EXISTS escrow_webhook_settings (
+  id BOOLEAN PRIMARY KEY DEFAULT TRUE CHECK (id),
+  recording_enabled BOOLEAN NOT NULL DEFAULT FALSE
+);
+
+INSERT INTO escrow_webhook_settings (id) VALUES (TRUE) ON CONFLICT DO NOTHING;
+
+CREATE OR REPLACE FUNCTION record_escrow_state_change() RETURNS TRIGGER AS $$
+BEGIN
+  IF NOT COALESCE((SELECT recording_enabled FROM escrow_webhook_settings), FALSE) THEN
+    RETURN NEW;
+  END IF;
+
+  INSERT INTO escrow_state_changes
+    (escrow_id, escrow_address, listing_id, old_status, new_status, amount, currency)
+  VALUES
+    (NEW.id, NEW.escrow_address, NEW.listing_id,
+     CASE WHEN TG_OP = 'UPDATE' THEN OLD.status ELSE NULL END,
+     NEW.status, NEW.amount, NEW.currency);
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS escrows_state_change_insert ON escrows;
+CREATE TRIGGER escrows_state_change_insert
+  AFTER INSERT ON escrows
+  FOR EACH ROW EXECUTE FUNCTION record_escrow_state_change();
+
+DROP TRIGGER IF EXISTS escrows_state_change_update ON escrows;
+CREATE TRIGGER escrows_state_change_update
+  AFTER UPDATE OF status ON escrows
+  FOR EACH ROW
+  WHEN (OLD.status IS DISTINCT FROM NEW.status)
+  EXECUTE FUNCTION record_escrow_state_change();
+
 CREATE INDEX IF NOT EXISTS idx_users_wallet_address ON users(wallet_address);
 CREATE INDEX IF NOT EXISTS idx_listings_seller_id ON listings(seller_id);
 CREATE INDEX IF NOT EXISTS idx_listings_status ON listings(status);
@@ -89,3 +153,5 @@ CREATE INDEX IF NOT EXISTS idx_escrows_status ON escrows(status);
 CREATE INDEX IF NOT EXISTS idx_reviews_reviewee_id ON reviews(reviewee_id);
 CREATE INDEX IF NOT EXISTS idx_notifications_user_id ON notifications(user_id);
 CREATE INDEX IF NOT EXISTS idx_notifications_is_read ON notifications(is_read);
+CREATE INDEX IF NOT EXISTS idx_escrow_state_changes_pending ON escrow_state_changes(escrow_id, id) WHERE delivered_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_escrow_state_changes_delivered_at ON escrow_state_changes(delivered_at) WHERE delivered_at IS NOT NULL;
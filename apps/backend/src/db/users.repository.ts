@@ -1,4 +1,4 @@
-import { query } from './index';
+import { query, Queryable } from './index';
 import { User } from '../types/index';
 
 export const findByWalletAddress = async (walletAddress: string): Promise<User | null> => {
@@ -51,6 +51,30 @@ export const updateProfile = async (
   return mapDbUserToUser(result.rows[0]);
 };
 
+// Replaces profile fields with a pseudonym (or clears them) while keeping the
+// row, since escrows and transactions still reference the user
+export const erasePersonalData = async (
+  id: string,
+  pseudonym: string | null,
+  db: Queryable = { query }
+): Promise<User | null> => {
+  const result = await db.query(
+    `UPDATE users 
+     SET username = $2, 
+         profile_image = NULL,
+         updated_at = NOW()
+     WHERE id = $1
+     RETURNING *`,
+    [id, pseudonym]
+  );
+
+  if (result.rows.length === 0) {
+    return null;
+  }
+
+  return mapDbUserToUser(result.rows[0]);
+};
+
 export const update = async (
   id: string,
   data: Partial<{
@@ -4,6 +4,7 @@ import logger from './utils/logger';
 import { connectRedis } from './utils/redis';
 import { runMigrations } from './db/migrations';
 import { WebSocketService } from './services/websocket.service';
+import { startEscrowWebhookDaemon, stopEscrowWebhookDaemon } from './services/escrow-webhooks.service';
 import { createServer } from 'http';
 import express from 'express';
 import cors from 'cors';
@@ -40,6 +41,8 @@ const startServer = async () => {
     const wsService = WebSocketService.getInstance();
     wsService.initialize(httpServer);
     
+    await startEscrowWebhookDaemon();
+    
     httpServer.listen(config.server.port, () => {
       logger.info(`Server running in ${config.server.env} mode on port ${config.server.port}`);
     });
@@ -56,6 +59,7 @@ const startServer = async () => {
 
     process.on('SIGTERM', () => {
       logger.info('SIGTERM received. Shutting down gracefully');
+      stopEscrowWebhookDaemon();
       httpServer.close(() => {
         logger.info('Process terminated');
       });
@@ -0,0 +1,52 @@
+// Loaded before the imports below so the configured network's currencies apply
+import 'dotenv/config';
+import { buildDeepLink, parseDeepLink, DeepLink, DeepLinkAction } from '../utils/deep-link';
+import { DisputeReasonCode } from '../types';
+
+const USAGE = [
+  'Usage:',
+  '  npm run deep-link -- parse <lumepay uri>',
+  '  npm run deep-link -- build <action> <escrow address> [amount=<n>] [currency=<c>] [listing=<id>] [reason=<code>]'
+].join('\n');
+
+function buildFromArgs(args: string[]): string {
+  const [action, escrowAddress, ...params] = args;
+  const link: DeepLink = { action: action as DeepLinkAction, escrowAddress };
+
+  for (const param of params) {
+    const [key, value] = param.split('=');
+    if (key === 'amount') {
+      link.amount = Number(value);
+    } else if (key === 'currency') {
+      link.currency = value;
+    } else if (key === 'listing') {
+      link.listingId = value;
+    } else if (key === 'reason') {
+      link.reasonCode = value as DisputeReasonCode;
+    } else {
+      throw new Error(`Unknown parameter: ${param}`);
+    }
+  }
+
+  return buildDeepLink(link);
+}
+
+function run(argv: string[]): void {
+  const [command, ...args] = argv;
+
+  if (command === 'parse' && args.length === 1) {
+    console.log(JSON.stringify(parseDeepLink(args[0]), null, 2));
+  } else if (command === 'build' && args.length >= 2) {
+    console.log(buildFromArgs(args));
+  } else {
+    console.error(USAGE);
+    process.exit(1);
+  }
+}
+
+try {
+  run(process.argv.slice(2));
+} catch (error: any) {
+  console.error(error.message);
+  process.exit(1);
+}
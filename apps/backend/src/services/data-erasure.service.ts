@@ -0,0 +1,154 @@
+import crypto from 'crypto';
+import { PublicKey } from '@solana/web3.js';
+import nacl from 'tweetnacl';
+import bs58 from 'bs58';
+import * as usersRepository from '../db/users.repository';
+import * as notificationsRepository from '../db/notifications.repository';
+import * as reviewsRepository from '../db/reviews.repository';
+import * as disputesRepository from '../db/disputes.repository';
+import * as credentialsRepository from '../db/credentials.repository';
+import { withTransaction } from '../db/index';
+import { BadRequestError, NotFoundError, UnauthorizedError } from '../utils/errors';
+import logger from '../utils/logger';
+
+export enum ErasureMode {
+  PSEUDONYMIZE = 'pseudonymize',
+  DELETE = 'delete'
+}
+
+export interface ErasureSummary {
+  userId: string;
+  mode: ErasureMode;
+  notificationsDeleted: number;
+  counterpartyNotificationsScrubbed: number;
+  reviewCommentsCleared: number;
+  disputeDetailsCleared: number;
+  credentialsDeleted: number;
+  erasedAt: Date;
+}
+
+export const ERASURE_REQUEST_MAX_AGE_MS = 10 * 60 * 1000;
+export const ERASED_DISPUTE_REASON = '[removed at user request]';
+// Stands in for the username in other users' notifications in delete mode
+export const ERASED_USERNAME = 'A former user';
+
+// The exact text the wallet owner signs to prove the request is theirs. The
+// mode is covered so a signed request cannot be replayed with a different one
+export function buildErasureMessage(walletAddress: string, requestedAt: number, mode: ErasureMode): string {
+  return `LumeSquare data erasure request\nWallet: ${walletAddress}\nMode: ${mode}\nRequested at: ${requestedAt}`;
+}
+
+export function verifyErasureSignature(
+  walletAddress: string,
+  requestedAt: number,
+  mode: ErasureMode,
+  signature: string
+): boolean {
+  try {
+    const message = new TextEncoder().encode(buildErasureMessage(walletAddress, requestedAt, mode));
+    return nacl.sign.detached.verify(
+      message,
+      bs58.decode(signature),
+      new PublicKey(walletAddress).toBytes()
+    );
+  } catch (error) {
+    return false;
+  }
+}
+
+// Stable per user so pseudonymized records stay linkable for accounting
+export function pseudonymFor(userId: string): string {
+  const digest = crypto.createHash('sha256').update(userId).digest('hex');
+  return `user-${digest.substring(0, 12)}`;
+}
+
+export function assertErasureMode(mode: unknown): asserts mode is ErasureMode {
+  if (!Object.values(ErasureMode).includes(mode as ErasureMode)) {
+    throw new BadRequestError('Invalid mode. Must be pseudonymize or delete');
+  }
+}
+
+/**
+ * Remove or pseudonymize personal data stored off-chain for a user, in one
+ * transaction so a failure leaves nothing half-erased. Review comments, the
+ * user's notifications and the reasons and details of their settled disputes
+ * are free text and are cleared; open disputes keep theirs for arbitration.
+ * The username is also replaced in notifications sent to escrow
+ * counterparties. Escrows, transactions and ratings are financial records
+ * mirrored from chain and are kept intact.
+ */
+export async function eraseUserData(userId: string, mode: ErasureMode = ErasureMode.PSEUDONYMIZE): Promise<ErasureSummary> {
+  assertErasureMode(mode);
+
+  const user = await usersRepository.findById(userId);
+
+  if (!user) {
+    throw new NotFoundError('User not found');
+  }
+
+  const pseudonym = mode === ErasureMode.PSEUDONYMIZE ? pseudonymFor(userId) : null;
+
+  let summary: ErasureSummary;
+  try {
+    summary = await withTransaction(async (client) => {
+      await usersRepository.erasePersonalData(userId, pseudonym, client);
+
+      // Statements on one client run in order, so these are awaited one by one
+      const counterpartyNotificationsScrubbed = user.username
+        ? await notificationsRepository.replaceTextForCounterparties(
+          userId,
+          user.username,
+          pseudonym || ERASED_USERNAME,
+          client
+        )
+        : 0;
+      const notificationsDeleted = await notificationsRepository.deleteAllNotificationsForUser(userId, client);
+      const reviewCommentsCleared = await reviewsRepository.clearCommentsByReviewer(userId, client);
+      const disputeDetailsCleared = await disputesRepository.clearDetailsByInitiator(userId, ERASED_DISPUTE_REASON, client);
+      const credentialsDeleted = await credentialsRepository.deleteByUserId(userId, client);
+
+      return {
+        userId,
+        mode,
+        notificationsDeleted,
+        counterpartyNotificationsScrubbed,
+        reviewCommentsCleared,
+        disputeDetailsCleared,
+        credentialsDeleted,
+        erasedAt: new Date()
+      };
+    });
+  } catch (error) {
+    logger.error(`Erasure for user ${userId} (${mode}) failed and was rolled back:`, error);
+    throw error;
+  }
+
+  logger.info(`Erased personal data for user ${userId} (${mode})`, summary);
+
+  return summary;
+}
+
+// Self-service erasure: the request must be signed by the user's wallet recently
+export async function requestErasure(
+  userId: string,
+  walletAddress: string,
+  requestedAt: number,
+  signature: string,
+  mode: ErasureMode = ErasureMode.PSEUDONYMIZE
+): Promise<ErasureSummary> {
+  if (!requestedAt || !signature) {
+    throw new BadRequestError('requestedAt and signature are required');
+  }
+
+  assertErasureMode(mode);
+
+  if (Math.abs(Date.now() - requestedAt) > ERASURE_REQUEST_MAX_AGE_MS) {
+    throw new BadRequestError('Erasure request has expired, please sign a new one');
+  }
+
+  if (!verifyErasureSignature(walletAddress, requestedAt, mode, signature)) {
+    throw new UnauthorizedError('Invalid erasure request signature');
+  }
+
+  return eraseUserData(userId, mode);
+}
@@ -0,0 +1,172 @@
+import { DisputeReason, DisputeReasonCode } from '../types';
+
+export const DEFAULT_LOCALE = 'en';
+
+type ReasonStrings = { label: string; description: string };
+
+const DISPUTE_REASON_CATALOG: { [locale: string]: { [code in DisputeReasonCode]: ReasonStrings } } = {
+  en: {
+    [DisputeReasonCode.ITEM_NOT_RECEIVED]: {
+      label: 'Item not received',
+      description: 'The buyer has not received the item by the agreed date.'
+    },
+    [DisputeReasonCode.ITEM_NOT_AS_DESCRIBED]: {
+      label: 'Item not as described',
+      description: 'The item received differs significantly from the listing.'
+    },
+    [DisputeReasonCode.ITEM_DAMAGED]: {
+      label: 'Item damaged',
+      description: 'The item arrived damaged or defective.'
+    },
+    [DisputeReasonCode.PAYMENT_NOT_RECEIVED]: {
+      label: 'Payment not received',
+      description: 'The seller has not received the expected payment.'
+    },
+    [DisputeReasonCode.UNAUTHORIZED_TRANSACTION]: {
+      label: 'Unauthorized transaction',
+      description: 'The transaction was not authorized by the account holder.'
+    },
+    [DisputeReasonCode.SELLER_UNRESPONSIVE]: {
+      label: 'Seller unresponsive',
+      description: 'The seller is not responding to messages.'
+    },
+    [DisputeReasonCode.BUYER_UNRESPONSIVE]: {
+      label: 'Buyer unresponsive',
+      description: 'The buyer is not responding to messages.'
+    },
+    [DisputeReasonCode.OTHER]: {
+      label: 'Other',
+      description: 'Another issue not covered by the options above.'
+    }
+  },
+  es: {
+    [DisputeReasonCode.ITEM_NOT_RECEIVED]: {
+      label: 'Artículo no recibido',
+      description: 'El comprador no ha recibido el artículo en la fecha acordada.'
+    },
+    [DisputeReasonCode.ITEM_NOT_AS_DESCRIBED]: {
+      label: 'Artículo no coincide con la descripción',
+      description: 'El artículo recibido difiere significativamente del anuncio.'
+    },
+    [DisputeReasonCode.ITEM_DAMAGED]: {
+      label: 'Artículo dañado',
+      description: 'El artículo llegó dañado o defectuoso.'
+    },
+    [DisputeReasonCode.PAYMENT_NOT_RECEIVED]: {
+      label: 'Pago no recibido',
+      description: 'El vendedor no ha recibido el pago esperado.'
+    },
+    [DisputeReasonCode.UNAUTHORIZED_TRANSACTION]: {
+      label: 'Transacción no autorizada',
+      description: 'El titular de la cuenta no autorizó la transacción.'
+    },
+    [DisputeReasonCode.SELLER_UNRESPONSIVE]: {
+      label: 'El vendedor no responde',
+      description: 'El vendedor no responde a los mensajes.'
+    },
+    [DisputeReasonCode.BUYER_UNRESPONSIVE]: {
+      label: 'El comprador no responde',
+      description: 'El comprador no responde a los mensajes.'
+    },
+    [DisputeReasonCode.OTHER]: {
+      label: 'Otro',
+      description: 'Otro problema no contemplado en las opciones anteriores.'
+    }
+  },
+  fr: {
+    [DisputeReasonCode.ITEM_NOT_RECEIVED]: {
+      label: 'Article non reçu',
+      description: "L'acheteur n'a pas reçu l'article à la date convenue."
+    },
+    [DisputeReasonCode.ITEM_NOT_AS_DESCRIBED]: {
+      label: 'Article non conforme à la description',
+      description: "L'article reçu diffère sensiblement de l'annonce."
+    },
+    [DisputeReasonCode.ITEM_DAMAGED]: {
+      label: 'Article endommagé',
+      description: "L'article est arrivé endommagé ou défectueux."
+    },
+    [DisputeReasonCode.PAYMENT_NOT_RECEIVED]: {
+      label: 'Paiement non reçu',
+      description: "Le vendeur n'a pas reçu le paiement attendu."
+    },
+    [DisputeReasonCode.UNAUTHORIZED_TRANSACTION]: {
+      label: 'Transaction non autorisée',
+      description: "La transaction n'a pas été autorisée par le titulaire du compte."
+    },
+    [DisputeReasonCode.SELLER_UNRESPONSIVE]: {
+      label: 'Vendeur injoignable',
+      description: 'Le vendeur ne répond pas aux messages.'
+    },
+    [DisputeReasonCode.BUYER_UNRESPONSIVE]: {
+      label: 'Acheteur injoignable',
+      description: "L'acheteur ne répond pas aux messages."
+    },
+    [DisputeReasonCode.OTHER]: {
+      label: 'Autre',
+      description: 'Un autre problème non couvert par les options ci-dessus.'
+    }
+  }
+};
+
+export function getSupportedLocales(): string[] {
+  return Object.keys(DISPUTE_REASON_CATALOG);
+}
+
+function isSupportedLocale(locale: string): boolean {
+  // Own keys only, so "constructor" or "__proto__" never resolve to a catalog
+  return Object.prototype.hasOwnProperty.call(DISPUTE_REASON_CATALOG, locale);
+}
+
+// Falls back to the base language ("es-MX" -> "es") and then to English
+export function resolveLocale(locale?: unknown): string {
+  if (!locale || typeof locale !== 'string') {
+    return DEFAULT_LOCALE;
+  }
+
+  const normalized = locale.toLowerCase();
+  if (isSupportedLocale(normalized)) {
+    return normalized;
+  }
+
+  const base = normalized.split(/[-_]/)[0];
+  return isSupportedLocale(base) ? base : DEFAULT_LOCALE;
+}
+
+export function isDisputeReasonCode(value: string): value is DisputeReasonCode {
+  return Object.values(DisputeReasonCode).includes(value as DisputeReasonCode);
+}
+
+export function getDisputeReasons(locale?: string): DisputeReason[] {
+  const strings = DISPUTE_REASON_CATALOG[resolveLocale(locale)];
+
+  return Object.values(DisputeReasonCode).map((code) => ({
+    code,
+    label: strings[code].label,
+    description: strings[code].description
+  }));
+}
+
+export function getDisputeReason(code: DisputeReasonCode, locale?: string): DisputeReason {
+  const strings = DISPUTE_REASON_CATALOG[resolveLocale(locale)][code];
+
+  return {
+    code,
+    label: strings.label,
+    description: strings.description
+  };
+}
+
+// Metadata attached to dispute notifications so every client can render the
+// reason in its own language without re-parsing free-form text
+export function buildDisputeReasonMetadata(code: DisputeReasonCode): Record<string, any> {
+  const labels: { [locale: string]: string } = {};
+  for (const locale of getSupportedLocales()) {
+    labels[locale] = DISPUTE_REASON_CATALOG[locale][code].label;
+  }
+
+  return {
+    reasonCode: code,
+    reasonLabels: labels
+  };
+}
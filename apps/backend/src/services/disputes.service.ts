@@ -1,8 +1,9 @@
 import * as disputesRepository from '../db/disputes.repository';
 import * as escrowsRepository from '../db/escrows.repository';
 import * as notificationsService from './notifications.service';
-import { Dispute, DisputeStatus, Escrow, EscrowStatus } from '../types';
-import { NotFoundError } from '../utils/errors';
+import * as disputeReasonsService from './dispute-reasons.service';
+import { Dispute, DisputeReasonCode, DisputeStatus, Escrow, EscrowStatus } from '../types';
+import { BadRequestError, NotFoundError } from '../utils/errors';
 import { EscrowService } from '../blockchain/escrow.service';
 
 async function transferFunds(
@@ -27,8 +28,14 @@ export async function createDispute(
   escrowId: string,
   userId: string,
   reason: string,
-  details?: string
+  details?: string,
+  reasonCode?: string
 ): Promise<Dispute> {
+  if (reasonCode && !disputeReasonsService.isDisputeReasonCode(reasonCode)) {
+    throw new BadRequestError(`Unknown dispute reason code: ${reasonCode}`);
+  }
+  const code = reasonCode as DisputeReasonCode | undefined;
+
   const escrow = await escrowsRepository.findById(escrowId);
   
   if (!escrow) {
@@ -45,13 +52,19 @@ export async function createDispute(
   
   await escrowsRepository.updateStatus(escrowId, EscrowStatus.DISPUTED);
   
-  const dispute = await disputesRepository.create(escrowId, userId, reason, details);
+  const disputeReason = reason || (code ? disputeReasonsService.getDisputeReason(code).label : reason);
+  const dispute = await disputesRepository.create(escrowId, userId, disputeReason, details, code);
   
   const otherPartyId = userId === escrow.buyerId ? escrow.sellerId : escrow.buyerId;
   
   await notificationsService.createDisputeNotification(
     otherPartyId,
-    `A dispute has been opened for escrow ${escrowId.substring(0, 8)}`
+    `A dispute has been opened for escrow ${escrowId.substring(0, 8)}`,
+    {
+      escrowId,
+      disputeId: dispute.id,
+      ...(code ? disputeReasonsService.buildDisputeReasonMetadata(code) : {})
+    }
   );
   
   return dispute;
@@ -0,0 +1,222 @@
+import crypto from 'crypto';
+import axios from 'axios';
+import config from '../config';
+import logger from '../utils/logger';
+import * as escrowStateChangesRepository from '../db/escrow-state-changes.repository';
+import { EscrowStateChange } from '../db/escrow-state-changes.repository';
+import { EscrowStatus } from '../types';
+import { NotFoundError } from '../utils/errors';
+
+export const SIGNATURE_HEADER = 'X-LumePay-Signature';
+export const TIMESTAMP_HEADER = 'X-LumePay-Timestamp';
+
+const REQUEST_TIMEOUT_MS = 10000;
+const CLAIM_BATCH_SIZE = 20;
+// Long enough for a whole batch to time out against every endpoint
+const CLAIM_LEASE_MS = 5 * 60 * 1000;
+const PRUNE_INTERVAL_MS = 60 * 60 * 1000;
+
+export interface EscrowWebhookPayload {
+  // Stable per transition. Delivery is at least once, so receivers must drop
+  // payloads whose id they have already processed
+  id: string;
+  type: 'escrow.state_changed';
+  escrowId: string;
+  escrowAddress: string | null;
+  listingId: string | null;
+  oldState: EscrowStatus | null;
+  newState: EscrowStatus;
+  amount: number;
+  currency: string;
+  occurredAt: string;
+}
+
+let pollTimer: NodeJS.Timeout | null = null;
+let isPolling = false;
+let lastPrunedAt = 0;
+
+export function buildPayload(change: EscrowStateChange): EscrowWebhookPayload {
+  return {
+    id: change.id,
+    type: 'escrow.state_changed',
+    escrowId: change.escrowId,
+    escrowAddress: change.escrowAddress,
+    listingId: change.listingId,
+    oldState: change.oldStatus,
+    newState: change.newStatus,
+    amount: change.amount,
+    currency: change.currency,
+    occurredAt: new Date(change.createdAt).toISOString()
+  };
+}
+
+/**
+ * HMAC-SHA256 over "<timestamp>.<body>", sent as sha256=<hex>. Including the
+ * timestamp lets receivers reject replays of old deliveries.
+ */
+export function signPayload(body: string, timestamp: number, secret: string): string {
+  const digest = crypto.createHmac('sha256', secret).update(`${timestamp}.${body}`).digest('hex');
+  return `sha256=${digest}`;
+}
+
+export function verifySignature(body: string, timestamp: number, signature: string, secret: string): boolean {
+  const expected = Buffer.from(signPayload(body, timestamp, secret));
+  const received = Buffer.from(signature);
+  return expected.length === received.length && crypto.timingSafeEqual(expected, received);
+}
+
+async function deliver(url: string, body: string): Promise<void> {
+  const timestamp = Math.floor(Date.now() / 1000);
+
+  await axios.post(url, body, {
+    timeout: REQUEST_TIMEOUT_MS,
+    headers: {
+      'Content-Type': 'application/json',
+      [TIMESTAMP_HEADER]: timestamp.toString(),
+      [SIGNATURE_HEADER]: signPayload(body, timestamp, config.webhooks.secret)
+    }
+  });
+}
+
+/**
+ * Deliver the next pending change of each escrow to the configured endpoints.
+ * Endpoints that accept a change are remembered and skipped on retry, and a
+ * change is marked delivered once every endpoint has it. Changes for the same
+ * escrow go out strictly in order: a failing change holds back the ones after
+ * it, and once dead-lettered it keeps doing so until an admin requeues or
+ * discards it.
+ */
+export async function dispatchPendingStateChanges(): Promise<number> {
+  const changes = await escrowStateChangesRepository.claimPending(CLAIM_LEASE_MS, CLAIM_BATCH_SIZE);
+  let delivered = 0;
+
+  for (const change of changes) {
+    const body = JSON.stringify(buildPayload(change));
+    const pendingUrls = config.webhooks.urls.filter((url) => !change.deliveredUrls.includes(url));
+    const results = await Promise.allSettled(pendingUrls.map((url) => deliver(url, body)));
+
+    const deliveredUrls = [...change.deliveredUrls];
+    const failures: string[] = [];
+    results.forEach((result, index) => {
+      if (result.status === 'fulfilled') {
+        deliveredUrls.push(pendingUrls[index]);
+      } else {
+        failures.push(`${pendingUrls[index]}: ${result.reason?.message || result.reason}`);
+      }
+    });
+
+    if (failures.length === 0) {
+      await escrowStateChangesRepository.markDelivered(change.id, deliveredUrls);
+      delivered++;
+      continue;
+    }
+
+    const exhausted = change.attempts + 1 >= config.webhooks.maxAttempts;
+    await escrowStateChangesRepository.recordFailedAttempt(change.id, failures.join('; '), deliveredUrls, exhausted);
+
+    if (exhausted) {
+      logger.error(`Dead-lettered webhook for escrow ${change.escrowId} (${change.oldStatus} -> ${change.newStatus}) after ${change.attempts + 1} attempts; later changes for this escrow are held until it is requeued or discarded`);
+    } else {
+      logger.warn(`Webhook delivery failed for escrow ${change.escrowId}, will retry:`, failures);
+    }
+  }
+
+  return delivered;
+}
+
+export async function getDeadLetters(): Promise<EscrowStateChange[]> {
+  return escrowStateChangesRepository.findDeadLetters();
+}
+
+export async function requeueDeadLetter(id: string): Promise<EscrowStateChange> {
+  const change = await escrowStateChangesRepository.requeueDeadLetter(id);
+
+  if (!change) {
+    throw new NotFoundError('Dead-lettered webhook not found');
+  }
+
+  logger.info(`Requeued dead-lettered webhook ${id} for escrow ${change.escrowId}`);
+  return change;
+}
+
+export async function discardDeadLetter(id: string): Promise<void> {
+  const deleted = await escrowStateChangesRepository.deleteDeadLetter(id);
+
+  if (!deleted) {
+    throw new NotFoundError('Dead-lettered webhook not found');
+  }
+
+  logger.info(`Discarded dead-lettered webhook ${id}`);
+}
+
+// Delivered changes are only kept long enough to investigate receiver issues
+export async function pruneDeliveredStateChanges(): Promise<number> {
+  const pruned = await escrowStateChangesRepository.pruneDelivered(config.webhooks.retentionDays);
+  lastPrunedAt = Date.now();
+
+  if (pruned > 0) {
+    logger.info(`Pruned ${pruned} delivered escrow state changes older than ${config.webhooks.retentionDays} days`);
+  }
+
+  return pruned;
+}
+
+export async function startEscrowWebhookDaemon(): Promise<void> {
+  if (pollTimer) return;
+
+  if (config.webhooks.urls.length === 0) {
+    logger.info('No escrow webhook URLs configured - webhook daemon disabled');
+    return;
+  }
+
+  if (!config.webhooks.secret) {
+    logger.warn('ESCROW_WEBHOOK_SECRET is not set - refusing to send unsigned escrow webhooks');
+    return;
+  }
+
+  // The escrows trigger only writes to the outbox once a daemon will drain it. The
+  // setting is shared by every instance, so one started without webhooks never
+  // turns it back off
+  try {
+    await escrowStateChangesRepository.enableRecording();
+  } catch (error) {
+    logger.error('Failed to enable escrow webhook recording:', error);
+  }
+
+  logger.info(`Starting escrow webhook daemon for ${config.webhooks.urls.length} endpoint(s)`);
+
+  pollTimer = setInterval(async () => {
+    // Skip a tick rather than overlap with a slow delivery round
+    if (isPolling) return;
+
+    isPolling = true;
+    try {
+      await dispatchPendingStateChanges();
+
+      if (Date.now() - lastPrunedAt >= PRUNE_INTERVAL_MS) {
+        await pruneDeliveredStateChanges();
+      }
+    } catch (error) {
+      logger.error('Error dispatching escrow webhooks:', error);
+    } finally {
+      isPolling = false;
+    }
+  }, config.webhooks.pollIntervalMs);
+}
+
+export function stopEscrowWebhookDaemon(): void {
+  if (pollTimer) {
+    clearInterval(pollTimer);
+    pollTimer = null;
+  }
+}
+
+export default {
+  startEscrowWebhookDaemon,
+  stopEscrowWebhookDaemon,
+  dispatchPendingStateChanges,
+  pruneDeliveredStateChanges,
+  getDeadLetters,
+  requeueDeadLetter,
+  discardDeadLetter
+};
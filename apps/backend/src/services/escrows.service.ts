@@ -1,7 +1,11 @@
 import * as escrowsRepository from '../db/escrows.repository';
 import * as listingsRepository from '../db/listings.repository';
 import * as usersRepository from '../db/users.repository';
-import { EscrowService as BlockchainEscrowService } from '../blockchain/escrow.service';
+import blockchainEscrowService, {
+  FundingPreflightResult,
+  FundingPrerequisite,
+  FundingPrerequisiteCheck
+} from '../blockchain/escrow.service';
 import { NotFoundError, BadRequestError, ForbiddenError } from '../utils/errors';
 import { Escrow, EscrowStatus, ListingStatus, TransactionStatus, DisputeResolutionMode, MultiSigStatus } from '../types';
 import logger from '../utils/logger';
@@ -11,7 +15,6 @@ import * as circleService from './circle.service';
 import reputationService from './reputation.service';
 import { v4 as uuidv4 } from 'uuid';
 
-const blockchainEscrowService = new BlockchainEscrowService();
 const HIGH_VALUE_THRESHOLD = 1000;
 
 export const createEscrow = async (
@@ -135,6 +138,61 @@ export const getEscrowById = async (id: string, userId: string): Promise<Escrow>
   return escrow;
 };
 
+export const preflightEscrowFunding = async (
+  buyerId: string,
+  listingId: string
+): Promise<FundingPreflightResult> => {
+  const buyer = await usersRepository.findById(buyerId);
+  if (!buyer) {
+    throw new NotFoundError('Buyer not found');
+  }
+  
+  const listing = await listingsRepository.findById(listingId);
+  if (!listing) {
+    throw new NotFoundError('Listing not found');
+  }
+  
+  const seller = await usersRepository.findById(listing.sellerId);
+  if (!seller) {
+    throw new NotFoundError('Seller not found');
+  }
+  
+  // The same conditions createEscrow rejects, reported alongside the on-chain checks
+  const listingChecks: FundingPrerequisiteCheck[] = [
+    {
+      prerequisite: FundingPrerequisite.LISTING_AVAILABLE,
+      satisfied: listing.status === ListingStatus.ACTIVE,
+      message: listing.status === ListingStatus.ACTIVE
+        ? 'Listing is available'
+        : 'Listing is not available'
+    },
+    {
+      prerequisite: FundingPrerequisite.BUYER_IS_NOT_SELLER,
+      satisfied: listing.sellerId !== buyerId,
+      message: listing.sellerId !== buyerId
+        ? 'Buyer is not the seller'
+        : 'You cannot buy your own listing'
+    }
+  ];
+  
+  const fundingResult = await blockchainEscrowService.preflightFunding(
+    buyer.walletAddress,
+    seller.walletAddress,
+    listing.price,
+    listing.currency,
+    listing.id
+  );
+  
+  const checks = [...listingChecks, ...fundingResult.checks];
+  const unmet = checks.filter((check) => !check.satisfied);
+  
+  return {
+    ready: unmet.length === 0,
+    checks,
+    unmet
+  };
+};
+
 export const getUserEscrows = async (
   userId: string,
   options: { role?: 'buyer' | 'seller'; status?: EscrowStatus; limit?: number; offset?: number } = {}
@@ -0,0 +1,192 @@
+import { PublicKey } from '@solana/web3.js';
+import { getAssociatedTokenAddress } from '@solana/spl-token';
+import * as listingsRepository from '../db/listings.repository';
+import * as usersRepository from '../db/users.repository';
+import escrowService from '../blockchain/escrow.service';
+import { solanaPayService } from './solana-pay.service';
+import * as notificationsService from './notifications.service';
+import { Listing, ListingStatus } from '../types';
+import { BadRequestError, NotFoundError } from '../utils/errors';
+import { parseCsvRecords } from '../utils/csv';
+import { buildDeepLink, DeepLinkAction } from '../utils/deep-link';
+import logger from '../utils/logger';
+
+export const MAX_IMPORT_ROWS = 5000;
+
+export interface ImportedListing {
+  row: number;
+  listingId: string;
+  title: string;
+  price: number;
+  currency: string;
+  escrow?: {
+    buyerWalletAddress: string;
+    escrowAddress: string;
+    vaultAddress: string;
+    fundingLink: string;
+  };
+}
+
+export interface FailedImportRow {
+  row: number;
+  error: string;
+}
+
+export interface ListingImportResult {
+  imported: ImportedListing[];
+  failed: FailedImportRow[];
+}
+
+/**
+ * Import listings from a CSV with columns
+ * title,price,currency[,description,category,buyer_wallet].
+ * Rows that name a buyer wallet also get their escrow PDA and vault token
+ * account derived up front, plus a lumepay: fund link for the buyer. No escrow
+ * is recorded or initialized here: the link routes the buyer into the normal
+ * purchase flow, which lands on the same addresses. Imported listings are plain listings; saving rows as
+ * reusable listing templates is not part of this import.
+ */
+export async function importListingsFromCsv(sellerId: string, csv: string): Promise<ListingImportResult> {
+  const seller = await usersRepository.findById(sellerId);
+
+  if (!seller) {
+    throw new NotFoundError('Seller not found');
+  }
+
+  const records = parseCsvRecords(csv);
+
+  if (records.length === 0) {
+    throw new BadRequestError('CSV must contain a header row and at least one listing');
+  }
+
+  if (records.length > MAX_IMPORT_ROWS) {
+    throw new BadRequestError(`CSV exceeds the maximum of ${MAX_IMPORT_ROWS} listings per import`);
+  }
+
+  const result: ListingImportResult = { imported: [], failed: [] };
+
+  for (let index = 0; index < records.length; index++) {
+    // Row numbers are 1-based and account for the header line
+    const row = index + 2;
+
+    try {
+      result.imported.push(await importRow(seller.walletAddress, sellerId, records[index], row));
+    } catch (error: any) {
+      result.failed.push({ row, error: error.message });
+    }
+  }
+
+  logger.info(`Imported ${result.imported.length} listings for seller ${sellerId} (${result.failed.length} failed)`);
+
+  await notificationsService.createListingNotification(
+    sellerId,
+    `Your bulk import finished: ${result.imported.length} listings created, ${result.failed.length} rows failed.`,
+    { imported: result.imported.length, failed: result.failed.length }
+  );
+
+  return result;
+}
+
+async function importRow(
+  sellerWalletAddress: string,
+  sellerId: string,
+  record: Record<string, string>,
+  row: number
+): Promise<ImportedListing> {
+  const { title, currency, description, category } = record;
+  const price = parseFloat(record.price);
+  const buyerWalletAddress = record.buyer_wallet;
+
+  if (!title || !currency || !record.price) {
+    throw new BadRequestError('Title, price, and currency are required');
+  }
+
+  if (isNaN(price) || price <= 0) {
+    throw new BadRequestError('Price must be greater than 0');
+  }
+
+  if (!solanaPayService.isSupportedCurrency(currency)) {
+    throw new BadRequestError(`Unsupported currency: ${currency}`);
+  }
+
+  let buyerPubkey: PublicKey | undefined;
+  let mintAddress: PublicKey | undefined;
+  if (buyerWalletAddress) {
+    try {
+      buyerPubkey = new PublicKey(buyerWalletAddress);
+    } catch (error) {
+      throw new BadRequestError(`Invalid buyer wallet address: ${buyerWalletAddress}`);
+    }
+
+    try {
+      mintAddress = new PublicKey(escrowService.getTokenMintAddress(currency));
+    } catch (error) {
+      throw new BadRequestError(`Escrows are not supported for currency: ${currency}`);
+    }
+  }
+
+  const listing = await listingsRepository.create({
+    sellerId,
+    title,
+    description: description || undefined,
+    price,
+    currency,
+    category: category || undefined,
+    images: [],
+    status: ListingStatus.ACTIVE
+  } as Omit<Listing, 'id' | 'createdAt' | 'updatedAt'>);
+
+  const imported: ImportedListing = {
+    row,
+    listingId: listing.id,
+    title: listing.title,
+    price,
+    currency
+  };
+
+  if (buyerPubkey && mintAddress) {
+    // The PDA is seeded with the listing id, so it can only be derived after the
+    // insert; drop the listing if that fails so a re-import does not duplicate it
+    try {
+      imported.escrow = await deriveEscrowAddresses(
+        new PublicKey(sellerWalletAddress),
+        buyerPubkey,
+        mintAddress,
+        listing
+      );
+    } catch (error) {
+      await listingsRepository.deleteById(listing.id);
+      throw error;
+    }
+  }
+
+  return imported;
+}
+
+async function deriveEscrowAddresses(
+  sellerPubkey: PublicKey,
+  buyerPubkey: PublicKey,
+  mintAddress: PublicKey,
+  listing: Listing
+): Promise<ImportedListing['escrow']> {
+  // Same derivation EscrowService.createEscrow uses for this listing
+  const [escrowPDA] = await escrowService.findEscrowPDA(sellerPubkey, buyerPubkey, listing.id);
+  const vault = await getAssociatedTokenAddress(
+    mintAddress,
+    escrowPDA,
+    true // Allow PDA as owner
+  );
+
+  return {
+    buyerWalletAddress: buyerPubkey.toString(),
+    escrowAddress: escrowPDA.toString(),
+    vaultAddress: vault.toString(),
+    fundingLink: buildDeepLink({
+      action: DeepLinkAction.FUND,
+      escrowAddress: escrowPDA.toString(),
+      amount: listing.price,
+      currency: listing.currency,
+      listingId: listing.id
+    })
+  };
+}
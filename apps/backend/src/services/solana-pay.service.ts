@@ -9,6 +9,8 @@ import * as usersRepository from '../db/users.repository';
 import { v4 as uuidv4 } from 'uuid';
 import bs58 from 'bs58';
 import { EscrowStatus } from '../types';
+import { buildDeepLink, DeepLinkAction } from '../utils/deep-link';
+import { ESCROW_CURRENCIES } from '../blockchain/escrow-mints';
 
 interface TokenAddressMap {
   [key: string]: string | undefined;
@@ -162,6 +164,7 @@ class SolanaPayService {
     if (paymentRequest.status === 'pending' && new Date() > paymentRequest.expiresAt) {
       paymentRequest.status = 'expired';
       this.paymentRequests.set(paymentId, paymentRequest);
+      await this.unwatchEscrowPayment(paymentRequest);
     }
     
     if (paymentRequest.status === 'pending') {
@@ -172,6 +175,7 @@ class SolanaPayService {
         if (signatures.length > 0) {
           paymentRequest.status = 'completed';
           this.paymentRequests.set(paymentId, paymentRequest);
+          await this.unwatchEscrowPayment(paymentRequest);
 
           const metadata = paymentRequest.metadata || {};
           if (metadata.escrowId) {
@@ -301,12 +305,13 @@ class SolanaPayService {
     listingId: string,
     currency = 'USDC',
     memo?: string
-  ): Promise<{ paymentId: string; qrCode: string; url: string; escrowAddress: string }> {
+  ): Promise<{ paymentId: string; qrCode: string; url: string; escrowAddress: string; deepLink?: string }> {
     try {
       const { escrowAddress, releaseTime } = await escrowService.createEscrow(
         sellerWalletAddress,
         buyerWalletAddress,
         amount,
+        listingId,
         currency
       );
       
@@ -333,6 +338,13 @@ class SolanaPayService {
         };
         this.paymentRequests.set(paymentId, paymentRequest);
         
+        // The escrow account and vault are read repeatedly until the buyer pays
+        try {
+          await escrowService.watchEscrow(escrowAddress, currency);
+        } catch (error: any) {
+          logger.warn(`Could not watch escrow ${escrowAddress} while awaiting payment:`, error);
+        }
+        
         await notificationsService.createEscrowNotification(
           buyerId,
           `Escrow created for listing #${listingId}. Please complete the payment.`,
@@ -360,11 +372,17 @@ class SolanaPayService {
       
       logger.info(`Created escrow payment request: ${paymentId} for escrow ${escrowAddress}`);
       
+      // Only escrow-held currencies have a lumepay: fund flow to route into
+      const deepLink = ESCROW_CURRENCIES.includes(currency)
+        ? buildDeepLink({ action: DeepLinkAction.FUND, escrowAddress, amount, currency, listingId })
+        : undefined;
+      
       return {
         paymentId,
         qrCode,
         url,
-        escrowAddress
+        escrowAddress,
+        deepLink
       };
     } catch (error: any) {
       logger.error('Error creating escrow payment:', error);
@@ -372,6 +390,23 @@ class SolanaPayService {
     }
   }
   
+  /**
+   * Stop keeping an escrow current once its payment request is no longer pending
+   */
+  private async unwatchEscrowPayment(paymentRequest: PaymentRequest): Promise<void> {
+    const escrowAddress = paymentRequest.metadata?.escrowId;
+    
+    if (!escrowAddress) {
+      return;
+    }
+    
+    try {
+      await escrowService.unwatchEscrow(escrowAddress, paymentRequest.currency);
+    } catch (error: any) {
+      logger.warn(`Could not unwatch escrow ${escrowAddress}:`, error);
+    }
+  }
+  
   /**
    * Send payment status notification to user
    */
@@ -483,6 +518,7 @@ class SolanaPayService {
         if (request.status === 'pending' && now > request.expiresAt) {
           request.status = 'expired';
           this.paymentRequests.set(id, request);
+          this.unwatchEscrowPayment(request);
           logger.info(`Payment request expired: ${id}`);
         }
       }
@@ -1,7 +1,7 @@
 import { Connection } from '@solana/web3.js';
 import logger from '../utils/logger';
 import * as notificationsService from './notifications.service';
-import { EscrowService } from '../blockchain/escrow.service';
+import escrowService from '../blockchain/escrow.service';
 import { EscrowStatus, TransactionStatus, NotificationType } from '../types';
 import * as escrowsRepository from '../db/escrows.repository';
 import * as usersRepository from '../db/users.repository';
@@ -76,7 +76,6 @@ function startMonitoring(): void {
     
     logger.debug(`Checking ${pendingTransactions.size} pending transactions`);
     
-    const escrowService = new EscrowService();
     const transactionsToRemove: string[] = [];
     
     for (const [id, transaction] of pendingTransactions.entries()) {
@@ -89,6 +89,7 @@ export interface Dispute {
   initiatorId: string;
   respondentId: string;
   reason: string;
+  reasonCode?: DisputeReasonCode;
   details?: string;
   status: DisputeStatus;
   resolution?: string;
@@ -168,6 +169,23 @@ export enum DisputeStatus {
   CLOSED = 'closed'
 }
 
+export enum DisputeReasonCode {
+  ITEM_NOT_RECEIVED = 'item_not_received',
+  ITEM_NOT_AS_DESCRIBED = 'item_not_as_described',
+  ITEM_DAMAGED = 'item_damaged',
+  PAYMENT_NOT_RECEIVED = 'payment_not_received',
+  UNAUTHORIZED_TRANSACTION = 'unauthorized_transaction',
+  SELLER_UNRESPONSIVE = 'seller_unresponsive',
+  BUYER_UNRESPONSIVE = 'buyer_unresponsive',
+  OTHER = 'other'
+}
+
+export interface DisputeReason {
+  code: DisputeReasonCode;
+  label: string;
+  description: string;
+}
+
 export enum VerificationLevel {
   NONE = 'none',
   BASIC = 'basic',
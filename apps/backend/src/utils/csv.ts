@@ -0,0 +1,66 @@
+// Minimal RFC 4180 parser: quoted fields, escaped quotes ("") and CRLF line endings
+export function parseCsv(text: string): string[][] {
+  const rows: string[][] = [];
+  let row: string[] = [];
+  let field = '';
+  let inQuotes = false;
+
+  for (let i = 0; i < text.length; i++) {
+    const char = text[i];
+
+    if (inQuotes) {
+      if (char === '"' && text[i + 1] === '"') {
+        field += '"';
+        i++;
+      } else if (char === '"') {
+        inQuotes = false;
+      } else {
+        field += char;
+      }
+      continue;
+    }
+
+    if (char === '"') {
+      inQuotes = true;
+    } else if (char === ',') {
+      row.push(field);
+      field = '';
+    } else if (char === '\n' || char === '\r') {
+      if (char === '\r' && text[i + 1] === '\n') {
+        i++;
+      }
+      row.push(field);
+      rows.push(row);
+      row = [];
+      field = '';
+    } else {
+      field += char;
+    }
+  }
+
+  if (field !== '' || row.length > 0) {
+    row.push(field);
+    rows.push(row);
+  }
+
+  return rows.filter((r) => r.some((value) => value.trim() !== ''));
+}
+
+// Parses a CSV with a header row into records keyed by lower-cased header names
+export function parseCsvRecords(text: string): Record<string, string>[] {
+  const [header, ...rows] = parseCsv(text);
+
+  if (!header) {
+    return [];
+  }
+
+  const keys = header.map((key) => key.trim().toLowerCase());
+
+  return rows.map((row) => {
+    const record: Record<string, string> = {};
+    keys.forEach((key, index) => {
+      record[key] = (row[index] || '').trim();
+    });
+    return record;
+  });
+}
@@ -0,0 +1,135 @@
+import { PublicKey } from '@solana/web3.js';
+import { DisputeReasonCode } from '../types';
+import { BadRequestError } from './errors';
+import { ESCROW_CURRENCIES } from '../blockchain/escrow-mints';
+
+export const LUMEPAY_URI_SCHEME = 'lumepay';
+
+export enum DeepLinkAction {
+  VIEW = 'view',
+  FUND = 'fund',
+  CONFIRM = 'confirm',
+  DISPUTE = 'dispute'
+}
+
+export interface DeepLink {
+  action: DeepLinkAction;
+  escrowAddress: string;
+  amount?: number;
+  currency?: string;
+  listingId?: string;
+  reasonCode?: DisputeReasonCode;
+}
+
+// Query parameters each action accepts; anything else is rejected
+const ALLOWED_PARAMS: { [action in DeepLinkAction]: string[] } = {
+  [DeepLinkAction.VIEW]: ['listing'],
+  [DeepLinkAction.FUND]: ['amount', 'currency', 'listing'],
+  [DeepLinkAction.CONFIRM]: ['listing'],
+  [DeepLinkAction.DISPUTE]: ['reason', 'listing']
+};
+
+export class DeepLinkError extends BadRequestError {
+  constructor(message: string) {
+    super(`Invalid LumePay link: ${message}`);
+  }
+}
+
+/**
+ * Build a link of the form lumepay:<action>/<escrow>?<params>, e.g.
+ * lumepay:fund/9xQe...?amount=25&currency=USDC
+ */
+export function buildDeepLink(link: DeepLink): string {
+  validateDeepLink(link);
+
+  const params = new URLSearchParams();
+  if (link.amount !== undefined) {
+    params.set('amount', link.amount.toString());
+  }
+  if (link.currency) {
+    params.set('currency', link.currency);
+  }
+  if (link.reasonCode) {
+    params.set('reason', link.reasonCode);
+  }
+  if (link.listingId) {
+    params.set('listing', link.listingId);
+  }
+
+  const query = params.toString();
+  return `${LUMEPAY_URI_SCHEME}:${link.action}/${link.escrowAddress}${query ? `?${query}` : ''}`;
+}
+
+export function parseDeepLink(uri: string): DeepLink {
+  let url: URL;
+  try {
+    url = new URL(uri.trim());
+  } catch (error) {
+    throw new DeepLinkError('not a valid URI');
+  }
+
+  if (url.protocol !== `${LUMEPAY_URI_SCHEME}:`) {
+    throw new DeepLinkError(`expected the ${LUMEPAY_URI_SCHEME}: scheme`);
+  }
+
+  const [action, escrowAddress, ...rest] = url.pathname.split('/');
+  if (!action || !escrowAddress || rest.length > 0) {
+    throw new DeepLinkError('expected lumepay:<action>/<escrow address>');
+  }
+
+  if (!Object.values(DeepLinkAction).includes(action as DeepLinkAction)) {
+    throw new DeepLinkError(`unknown action "${action}"`);
+  }
+
+  const allowed = ALLOWED_PARAMS[action as DeepLinkAction];
+  for (const key of url.searchParams.keys()) {
+    if (!allowed.includes(key)) {
+      throw new DeepLinkError(`parameter "${key}" is not supported for ${action}`);
+    }
+  }
+
+  const amount = url.searchParams.get('amount');
+  const link: DeepLink = {
+    action: action as DeepLinkAction,
+    escrowAddress,
+    amount: amount !== null ? Number(amount) : undefined,
+    currency: url.searchParams.get('currency') || undefined,
+    listingId: url.searchParams.get('listing') || undefined,
+    reasonCode: (url.searchParams.get('reason') || undefined) as DisputeReasonCode | undefined
+  };
+
+  validateDeepLink(link);
+  return link;
+}
+
+function validateDeepLink(link: DeepLink): void {
+  if (!Object.values(DeepLinkAction).includes(link.action)) {
+    throw new DeepLinkError(`unknown action "${link.action}"`);
+  }
+
+  try {
+    new PublicKey(link.escrowAddress);
+  } catch (error) {
+    throw new DeepLinkError(`"${link.escrowAddress}" is not a valid escrow address`);
+  }
+
+  if (link.action === DeepLinkAction.FUND) {
+    if (link.amount === undefined || !Number.isFinite(link.amount) || link.amount <= 0) {
+      throw new DeepLinkError('fund links require a positive amount');
+    }
+    if (!link.currency || !ESCROW_CURRENCIES.includes(link.currency)) {
+      throw new DeepLinkError(`fund links require one of the currencies ${ESCROW_CURRENCIES.join(', ')}`);
+    }
+  } else if (link.amount !== undefined || link.currency !== undefined) {
+    throw new DeepLinkError(`${link.action} links do not take an amount or currency`);
+  }
+
+  if (link.reasonCode !== undefined) {
+    if (link.action !== DeepLinkAction.DISPUTE) {
+      throw new DeepLinkError(`${link.action} links do not take a dispute reason`);
+    }
+    if (!Object.values(DisputeReasonCode).includes(link.reasonCode)) {
+      throw new DeepLinkError(`unknown dispute reason "${link.reasonCode}"`);
+    }
+  }
+}
@@ -0,0 +1,79 @@
+import { Request, Response } from 'express';
+import * as deepLinksController from '../../../src/api/controllers/deep-links.controller';
+import { BadRequestError } from '../../../src/utils/errors';
+import { DeepLinkAction, DeepLinkError } from '../../../src/utils/deep-link';
+
+describe('Deep Links Controller', () => {
+  const escrowAddress = '9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin';
+  let mockRequest: Partial<Request>;
+  let mockResponse: Partial<Response>;
+  let mockNext: jest.Mock;
+
+  beforeEach(() => {
+    mockRequest = {
+      body: {},
+      params: {},
+      query: {}
+    };
+
+    mockResponse = {
+      status: jest.fn().mockReturnThis(),
+      json: jest.fn()
+    };
+
+    mockNext = jest.fn();
+  });
+
+  describe('parseLink', () => {
+    it('should return the parsed link in canonical form', async () => {
+      mockRequest.body = { uri: ` lumepay:fund/${escrowAddress}?currency=USDC&amount=25 ` };
+
+      await deepLinksController.parseLink(mockRequest as Request, mockResponse as Response, mockNext);
+
+      expect(mockResponse.status).toHaveBeenCalledWith(200);
+      expect(mockResponse.json).toHaveBeenCalledWith({
+        status: 'success',
+        data: {
+          link: expect.objectContaining({ action: DeepLinkAction.FUND, escrowAddress, amount: 25, currency: 'USDC' }),
+          uri: `lumepay:fund/${escrowAddress}?amount=25&currency=USDC`
+        }
+      });
+    });
+
+    it('should reject invalid links', async () => {
+      mockRequest.body = { uri: `lumepay:fund/${escrowAddress}?amount=25&currency=SOL` };
+
+      await deepLinksController.parseLink(mockRequest as Request, mockResponse as Response, mockNext);
+
+      expect(mockNext).toHaveBeenCalledWith(expect.any(DeepLinkError));
+      expect(mockResponse.json).not.toHaveBeenCalled();
+    });
+
+    it('should require a URI', async () => {
+      await deepLinksController.parseLink(mockRequest as Request, mockResponse as Response, mockNext);
+
+      expect(mockNext).toHaveBeenCalledWith(expect.any(BadRequestError));
+    });
+  });
+
+  describe('buildLink', () => {
+    it('should build a link from its parts', async () => {
+      mockRequest.body = { action: DeepLinkAction.CONFIRM, escrowAddress };
+
+      await deepLinksController.buildLink(mockRequest as Request, mockResponse as Response, mockNext);
+
+      expect(mockResponse.json).toHaveBeenCalledWith({
+        status: 'success',
+        data: { uri: `lumepay:confirm/${escrowAddress}` }
+      });
+    });
+
+    it('should require an action and escrow address', async () => {
+      mockRequest.body = { escrowAddress };
+
+      await deepLinksController.buildLink(mockRequest as Request, mockResponse as Response, mockNext);
+
+      expect(mockNext).toHaveBeenCalledWith(expect.any(BadRequestError));
+    });
+  });
+});
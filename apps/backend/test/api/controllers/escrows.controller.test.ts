@@ -129,6 +129,56 @@ describe('Escrows Controller', () => {
     });
   });
   
+  describe('preflightEscrow', () => {
+    it('should return the funding checklist', async () => {
+      // Setup
+      mockRequest.body = {
+        listingId: 'listing-123'
+      };
+      
+      const mockPreflight = {
+        ready: false,
+        checks: [
+          { prerequisite: 'buyer_token_account', satisfied: true, message: 'Buyer has a USDC token account' },
+          { prerequisite: 'seller_token_account', satisfied: false, message: 'Seller needs a USDC token account to receive the payout' }
+        ],
+        unmet: [
+          { prerequisite: 'seller_token_account', satisfied: false, message: 'Seller needs a USDC token account to receive the payout' }
+        ]
+      };
+      
+      (escrowsService.preflightEscrowFunding as jest.Mock).mockResolvedValue(mockPreflight);
+      
+      // Execute
+      await escrowsController.preflightEscrow(
+        mockRequest as Request,
+        mockResponse as Response,
+        mockNext
+      );
+      
+      // Assert
+      expect(escrowsService.preflightEscrowFunding).toHaveBeenCalledWith('user-123', 'listing-123');
+      expect(mockResponse.status).toHaveBeenCalledWith(200);
+      expect(mockResponse.json).toHaveBeenCalledWith({
+        status: 'success',
+        data: { preflight: mockPreflight }
+      });
+    });
+    
+    it('should handle missing listing ID', async () => {
+      // Execute
+      await escrowsController.preflightEscrow(
+        mockRequest as Request,
+        mockResponse as Response,
+        mockNext
+      );
+      
+      // Assert
+      expect(mockNext).toHaveBeenCalledWith(expect.any(BadRequestError));
+      expect(escrowsService.preflightEscrowFunding).not.toHaveBeenCalled();
+    });
+  });
+  
   describe('getUserEscrows', () => {
     it('should get all escrows for a user successfully', async () => {
       // Setup
@@ -0,0 +1,151 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+import { EscrowAccountCache } from '../../src/blockchain/escrow-account-cache';
+
+jest.mock('@solana/web3.js', () => ({
+  PublicKey: jest.fn().mockImplementation((key: string) => ({
+    toString: () => key
+  }))
+}));
+
+jest.mock('../../src/utils/logger', () => ({
+  info: jest.fn(),
+  error: jest.fn(),
+  warn: jest.fn(),
+  debug: jest.fn(),
+}));
+
+describe('EscrowAccountCache', () => {
+  const escrowAddress = new PublicKey('escrow-123');
+  const accountAt = (slot: number) => ({ data: Buffer.from([slot]), lamports: 1, owner: 'program', executable: false });
+
+  let mockConnection: {
+    getAccountInfoAndContext: jest.Mock;
+    onAccountChange: jest.Mock;
+    removeAccountChangeListener: jest.Mock;
+  };
+  let cache: EscrowAccountCache;
+
+  beforeEach(() => {
+    jest.clearAllMocks();
+
+    mockConnection = {
+      getAccountInfoAndContext: jest.fn().mockImplementation(async () => ({
+        context: { slot: 100 },
+        value: accountAt(100)
+      })),
+      onAccountChange: jest.fn().mockReturnValue(7),
+      removeAccountChangeListener: jest.fn().mockResolvedValue(undefined)
+    };
+    cache = new EscrowAccountCache(mockConnection as unknown as Connection, { ttlMs: 60 * 1000 });
+  });
+
+  it('should serve repeated reads from the cache', async () => {
+    await cache.get(escrowAddress);
+    const account = await cache.get(escrowAddress);
+
+    expect(account).toEqual(accountAt(100));
+    expect(mockConnection.getAccountInfoAndContext).toHaveBeenCalledTimes(1);
+  });
+
+  it('should refetch when the cached slot is older than requested', async () => {
+    await cache.get(escrowAddress);
+    mockConnection.getAccountInfoAndContext.mockResolvedValueOnce({
+      context: { slot: 120 },
+      value: accountAt(120)
+    });
+
+    const account = await cache.get(escrowAddress, 110);
+
+    expect(account).toEqual(accountAt(120));
+    expect(mockConnection.getAccountInfoAndContext).toHaveBeenLastCalledWith(escrowAddress, {
+      commitment: 'confirmed',
+      minContextSlot: 110
+    });
+  });
+
+  it('should drop and refetch entries after the ttl', async () => {
+    cache = new EscrowAccountCache(mockConnection as unknown as Connection, { ttlMs: 0 });
+
+    await cache.get(escrowAddress);
+    await cache.get(escrowAddress);
+
+    expect(mockConnection.getAccountInfoAndContext).toHaveBeenCalledTimes(2);
+    expect(cache.size).toBe(1);
+  });
+
+  it('should evict the least recently used entry beyond the size cap', async () => {
+    cache = new EscrowAccountCache(mockConnection as unknown as Connection, { maxEntries: 2 });
+    const [first, second, third] = ['escrow-1', 'escrow-2', 'escrow-3'].map((key) => new PublicKey(key));
+
+    await cache.get(first);
+    await cache.get(second);
+    // Touch the first entry so the second becomes the oldest
+    await cache.get(first);
+    await cache.get(third);
+
+    expect(cache.size).toBe(2);
+    expect(mockConnection.getAccountInfoAndContext).toHaveBeenCalledTimes(3);
+
+    await cache.get(first);
+    expect(mockConnection.getAccountInfoAndContext).toHaveBeenCalledTimes(3);
+    await cache.get(second);
+    expect(mockConnection.getAccountInfoAndContext).toHaveBeenCalledTimes(4);
+  });
+
+  it('should refresh after a transaction lands in a newer slot', async () => {
+    await cache.get(escrowAddress);
+    mockConnection.getAccountInfoAndContext.mockResolvedValueOnce({
+      context: { slot: 150 },
+      value: accountAt(150)
+    });
+
+    await cache.recordTransaction(escrowAddress, 150);
+    const account = await cache.get(escrowAddress, 150);
+
+    expect(account).toEqual(accountAt(150));
+    expect(mockConnection.getAccountInfoAndContext).toHaveBeenCalledTimes(2);
+  });
+
+  it('should keep watched accounts current from change notifications', async () => {
+    cache = new EscrowAccountCache(mockConnection as unknown as Connection, { ttlMs: 0 });
+
+    await cache.watch(escrowAddress);
+    const onChange = mockConnection.onAccountChange.mock.calls[0][1];
+    onChange(accountAt(130), { slot: 130 });
+
+    // Watched entries do not expire, so no further reads are made
+    const account = await cache.get(escrowAddress, 130);
+
+    expect(account).toEqual(accountAt(130));
+    expect(mockConnection.getAccountInfoAndContext).toHaveBeenCalledTimes(1);
+  });
+
+  it('should ignore notifications older than the cached slot', async () => {
+    await cache.watch(escrowAddress);
+    const onChange = mockConnection.onAccountChange.mock.calls[0][1];
+    onChange(accountAt(90), { slot: 90 });
+
+    expect(await cache.get(escrowAddress)).toEqual(accountAt(100));
+  });
+
+  it('should drop the subscription and entry on unwatch', async () => {
+    await cache.watch(escrowAddress);
+    await cache.unwatch(escrowAddress);
+
+    expect(mockConnection.removeAccountChangeListener).toHaveBeenCalledWith(7);
+    expect(cache.isWatched(escrowAddress)).toBe(false);
+    expect(cache.size).toBe(0);
+  });
+
+  it('should stop watching accounts evicted beyond the size cap', async () => {
+    cache = new EscrowAccountCache(mockConnection as unknown as Connection, { maxEntries: 1 });
+    const [first, second] = ['escrow-1', 'escrow-2'].map((key) => new PublicKey(key));
+
+    await cache.watch(first);
+    await cache.get(second);
+
+    expect(cache.isWatched(first)).toBe(false);
+    expect(cache.watchedCount).toBe(0);
+    expect(mockConnection.removeAccountChangeListener).toHaveBeenCalledWith(7);
+  });
+});
@@ -1,8 +1,8 @@
-import { EscrowService } from '../../src/blockchain/escrow.service';
+import { EscrowService, FundingPrerequisite } from '../../src/blockchain/escrow.service';
 import { StablecoinType } from '../../src/services/stablecoin.service';
-import { BlockchainError } from '../../src/utils/errors';
+import { BadRequestError, BlockchainError } from '../../src/utils/errors';
 import { Connection, Keypair, PublicKey, Transaction } from '@solana/web3.js';
-import { getAssociatedTokenAddress, createTransferInstruction } from '@solana/spl-token';
+import { getAssociatedTokenAddress, createTransferInstruction, getAccount, TokenAccountNotFoundError, unpackAccount } from '@solana/spl-token';
 import * as bs58 from 'bs58';
 
 // Mocks
@@ -20,7 +20,10 @@ jest.mock('@solana/web3.js', () => {
       blockhash: 'mock-blockhash',
       lastValidBlockHeight: 999999
     }),
-    sendAndConfirmTransaction: jest.fn().mockResolvedValue('mockSignature123')
+    sendAndConfirmTransaction: jest.fn().mockResolvedValue('mockSignature123'),
+    getBalance: jest.fn(),
+    getMinimumBalanceForRentExemption: jest.fn().mockResolvedValue(2039280),
+    getAccountInfoAndContext: jest.fn().mockResolvedValue({ context: { slot: 100 }, value: null })
   };
   
   return {
@@ -68,7 +71,11 @@ jest.mock('@solana/spl-token', () => ({
     toString: () => 'mockTokenAccount',
     toBase58: () => 'mockTokenAccount'
   }),
-  createTransferInstruction: jest.fn().mockReturnValue({})
+  createTransferInstruction: jest.fn().mockReturnValue({}),
+  getAccount: jest.fn(),
+  unpackAccount: jest.fn().mockReturnValue({ amount: BigInt(0) }),
+  TokenAccountNotFoundError: class TokenAccountNotFoundError extends Error {},
+  ACCOUNT_SIZE: 165
 }));
 
 jest.mock('bs58', () => ({
@@ -109,7 +116,8 @@ describe('Escrow Service', () => {
       const result = await escrowService.createEscrow(
         buyerWalletAddress,
         sellerWalletAddress,
-        amount
+        amount,
+        'listing-123'
       );
       
       // Assert
@@ -144,7 +152,7 @@ describe('Escrow Service', () => {
       
       // Act & Assert
       await expect(
-        escrowService.createEscrow(buyerWalletAddress, sellerWalletAddress, amount)
+        escrowService.createEscrow(buyerWalletAddress, sellerWalletAddress, amount, 'listing-123')
       ).rejects.toThrow(BlockchainError);
       
       // Restore original implementation
@@ -246,6 +254,119 @@ describe('Escrow Service', () => {
     });
   });
   
+  describe('preflightFunding', () => {
+    const buyerWalletAddress = 'buyerWalletAddress';
+    const sellerWalletAddress = 'sellerWalletAddress';
+    let mockConnection: {
+      getBalance: jest.Mock;
+      getMinimumBalanceForRentExemption: jest.Mock;
+      getAccountInfoAndContext: jest.Mock;
+    };
+
+    beforeEach(() => {
+      mockConnection = (escrowService as any).connection;
+      mockConnection.getBalance.mockResolvedValue(1000000000);
+      jest.spyOn(escrowService, 'findEscrowPDA').mockResolvedValue([new PublicKey('escrowAddress123'), 255]);
+    });
+
+    it('should be ready when the buyer holds exactly the listing price', async () => {
+      // Token accounts are read buyer first, then seller
+      (getAccount as jest.Mock)
+        .mockResolvedValueOnce({ amount: BigInt(100000000) })
+        .mockResolvedValueOnce({ amount: BigInt(0) });
+
+      const result = await escrowService.preflightFunding(buyerWalletAddress, sellerWalletAddress, 100);
+
+      expect(result.ready).toBe(true);
+      expect(result.unmet).toEqual([]);
+      const balance = result.checks.find((check) => check.prerequisite === FundingPrerequisite.BUYER_TOKEN_BALANCE);
+      expect(balance).toMatchObject({ satisfied: true, required: '100000000', available: '100000000' });
+    });
+
+    it('should report a short token balance', async () => {
+      (getAccount as jest.Mock)
+        .mockResolvedValueOnce({ amount: BigInt(99999999) })
+        .mockResolvedValueOnce({ amount: BigInt(0) });
+
+      const result = await escrowService.preflightFunding(buyerWalletAddress, sellerWalletAddress, 100);
+
+      expect(result.ready).toBe(false);
+      expect(result.unmet.map((check) => check.prerequisite)).toEqual([FundingPrerequisite.BUYER_TOKEN_BALANCE]);
+    });
+
+    it('should report missing token accounts', async () => {
+      (getAccount as jest.Mock)
+        .mockRejectedValueOnce(new TokenAccountNotFoundError())
+        .mockRejectedValueOnce(new TokenAccountNotFoundError());
+
+      const result = await escrowService.preflightFunding(buyerWalletAddress, sellerWalletAddress, 100);
+
+      expect(result.ready).toBe(false);
+      expect(result.unmet.map((check) => check.prerequisite)).toEqual([
+        FundingPrerequisite.BUYER_TOKEN_ACCOUNT,
+        FundingPrerequisite.BUYER_TOKEN_BALANCE,
+        FundingPrerequisite.SELLER_TOKEN_ACCOUNT
+      ]);
+      expect(result.checks).toHaveLength(4);
+    });
+
+    it('should add vault rent to the SOL requirement when the vault does not exist', async () => {
+      mockConnection.getBalance.mockResolvedValue(5000);
+      // The escrow vault is read through the account cache, and does not exist
+      (getAccount as jest.Mock)
+        .mockResolvedValueOnce({ amount: BigInt(100000000) })
+        .mockResolvedValueOnce({ amount: BigInt(0) });
+
+      const result = await escrowService.preflightFunding(
+        buyerWalletAddress, sellerWalletAddress, 100, 'USDC', 'listing-123'
+      );
+
+      expect(result.unmet).toEqual([
+        expect.objectContaining({
+          prerequisite: FundingPrerequisite.BUYER_SOL_BALANCE,
+          required: (5000 + 2039280).toString(),
+          available: '5000'
+        })
+      ]);
+    });
+
+    it('should only require the signature fee when the vault already exists', async () => {
+      mockConnection.getBalance.mockResolvedValue(5000);
+      mockConnection.getAccountInfoAndContext.mockResolvedValueOnce({
+        context: { slot: 100 },
+        value: { data: Buffer.alloc(165), lamports: 2039280, owner: 'mockTokenProgramId', executable: false }
+      });
+      (getAccount as jest.Mock)
+        .mockResolvedValueOnce({ amount: BigInt(100000000) })
+        .mockResolvedValueOnce({ amount: BigInt(0) });
+
+      const result = await escrowService.preflightFunding(
+        buyerWalletAddress, sellerWalletAddress, 100, 'USDC', 'listing-123'
+      );
+
+      expect(result.ready).toBe(true);
+      expect(escrowService.findEscrowPDA).toHaveBeenCalledWith(
+        expect.anything(), expect.anything(), 'listing-123'
+      );
+      expect(unpackAccount).toHaveBeenCalled();
+      expect(mockConnection.getMinimumBalanceForRentExemption).not.toHaveBeenCalled();
+    });
+
+    it('should reject currencies the escrow cannot hold', async () => {
+      await expect(
+        escrowService.preflightFunding(buyerWalletAddress, sellerWalletAddress, 1, 'SOL')
+      ).rejects.toThrow(BadRequestError);
+    });
+
+    it('should surface RPC failures as BlockchainError', async () => {
+      (getAccount as jest.Mock).mockRejectedValueOnce(new Error('429 Too Many Requests'));
+
+      await expect(
+        escrowService.preflightFunding(buyerWalletAddress, sellerWalletAddress, 100)
+      ).rejects.toThrow(BlockchainError);
+    });
+  });
+  
   describe('verifyTransaction', () => {
     it('should verify a simulated transaction', async () => {
       // Arrange
@@ -0,0 +1,66 @@
+import { DisputeReasonCode, DisputeStatus } from '../../src/types';
+
+jest.mock('../../src/db/index', () => {
+  return {
+    query: jest.fn()
+  };
+});
+
+import * as disputesRepository from '../../src/db/disputes.repository';
+import { query } from '../../src/db/index';
+
+const mockQuery = query as jest.Mock;
+
+describe('Disputes Repository', () => {
+  beforeEach(() => {
+    jest.clearAllMocks();
+  });
+
+  describe('create', () => {
+    it('should store and return the reason code', async () => {
+      mockQuery
+        .mockResolvedValueOnce({ rows: [{ id: 'escrow-123', buyer_id: 'buyer-123', seller_id: 'seller-123' }] })
+        .mockImplementationOnce(async (_sql: string, params: any[]) => ({
+          rows: [{
+            id: params[0],
+            escrow_id: params[1],
+            initiator_id: params[2],
+            respondent_id: params[3],
+            reason: params[4],
+            reason_code: params[5],
+            details: params[6],
+            status: params[7],
+            created_at: params[8],
+            updated_at: params[9]
+          }]
+        }));
+
+      const result = await disputesRepository.create(
+        'escrow-123',
+        'buyer-123',
+        'Item not received',
+        undefined,
+        DisputeReasonCode.ITEM_NOT_RECEIVED
+      );
+
+      expect(mockQuery.mock.calls[1][0]).toContain('reason_code');
+      expect(result).toEqual(expect.objectContaining({
+        respondentId: 'seller-123',
+        reason: 'Item not received',
+        reasonCode: DisputeReasonCode.ITEM_NOT_RECEIVED,
+        status: DisputeStatus.OPEN
+      }));
+    });
+
+    it('should leave the reason code unset for free-text reasons', async () => {
+      mockQuery
+        .mockResolvedValueOnce({ rows: [{ id: 'escrow-123', buyer_id: 'buyer-123', seller_id: 'seller-123' }] })
+        .mockResolvedValueOnce({ rows: [{ id: 'dispute-123', reason: 'Wrong colour', reason_code: null }] });
+
+      const result = await disputesRepository.create('escrow-123', 'buyer-123', 'Wrong colour');
+
+      expect(mockQuery.mock.calls[1][1][5]).toBeNull();
+      expect(result.reasonCode).toBeUndefined();
+    });
+  });
+});
@@ -0,0 +1,145 @@
+import nacl from 'tweetnacl';
+import bs58 from 'bs58';
+import { Keypair } from '@solana/web3.js';
+import * as dataErasureService from '../../src/services/data-erasure.service';
+import { ErasureMode } from '../../src/services/data-erasure.service';
+import * as usersRepository from '../../src/db/users.repository';
+import * as notificationsRepository from '../../src/db/notifications.repository';
+import * as reviewsRepository from '../../src/db/reviews.repository';
+import * as disputesRepository from '../../src/db/disputes.repository';
+import * as credentialsRepository from '../../src/db/credentials.repository';
+import { withTransaction } from '../../src/db/index';
+import { BadRequestError, NotFoundError, UnauthorizedError } from '../../src/utils/errors';
+
+jest.mock('../../src/db/users.repository');
+jest.mock('../../src/db/notifications.repository');
+jest.mock('../../src/db/reviews.repository');
+jest.mock('../../src/db/disputes.repository');
+jest.mock('../../src/db/credentials.repository');
+jest.mock('../../src/db/index', () => ({
+  withTransaction: jest.fn()
+}));
+jest.mock('../../src/utils/logger', () => ({
+  info: jest.fn(),
+  error: jest.fn(),
+  warn: jest.fn(),
+  debug: jest.fn(),
+}));
+
+describe('Data Erasure Service', () => {
+  const wallet = Keypair.generate();
+  const walletAddress = wallet.publicKey.toString();
+  const userId = 'user-123';
+  const transactionClient = { query: jest.fn() };
+
+  const sign = (requestedAt: number, mode: ErasureMode = ErasureMode.PSEUDONYMIZE) => bs58.encode(nacl.sign.detached(
+    new TextEncoder().encode(dataErasureService.buildErasureMessage(walletAddress, requestedAt, mode)),
+    wallet.secretKey
+  ));
+
+  beforeEach(() => {
+    jest.clearAllMocks();
+
+    (withTransaction as jest.Mock).mockImplementation(async (work) => work(transactionClient));
+    (usersRepository.findById as jest.Mock).mockResolvedValue({ id: userId, walletAddress, username: 'alice' });
+    (usersRepository.erasePersonalData as jest.Mock).mockResolvedValue(undefined);
+    (notificationsRepository.replaceTextForCounterparties as jest.Mock).mockResolvedValue(3);
+    (notificationsRepository.deleteAllNotificationsForUser as jest.Mock).mockResolvedValue(4);
+    (reviewsRepository.clearCommentsByReviewer as jest.Mock).mockResolvedValue(2);
+    (disputesRepository.clearDetailsByInitiator as jest.Mock).mockResolvedValue(1);
+    (credentialsRepository.deleteByUserId as jest.Mock).mockResolvedValue(1);
+  });
+
+  describe('eraseUserData', () => {
+    it('should pseudonymize the profile and clear free-text records', async () => {
+      const summary = await dataErasureService.eraseUserData(userId, ErasureMode.PSEUDONYMIZE);
+
+      expect(usersRepository.erasePersonalData).toHaveBeenCalledWith(
+        userId, dataErasureService.pseudonymFor(userId), transactionClient
+      );
+      expect(notificationsRepository.replaceTextForCounterparties).toHaveBeenCalledWith(
+        userId, 'alice', dataErasureService.pseudonymFor(userId), transactionClient
+      );
+      expect(disputesRepository.clearDetailsByInitiator).toHaveBeenCalledWith(
+        userId, dataErasureService.ERASED_DISPUTE_REASON, transactionClient
+      );
+      expect(summary).toMatchObject({
+        userId,
+        mode: ErasureMode.PSEUDONYMIZE,
+        notificationsDeleted: 4,
+        counterpartyNotificationsScrubbed: 3,
+        reviewCommentsCleared: 2,
+        disputeDetailsCleared: 1,
+        credentialsDeleted: 1
+      });
+    });
+
+    it('should clear the username entirely in delete mode', async () => {
+      await dataErasureService.eraseUserData(userId, ErasureMode.DELETE);
+
+      expect(usersRepository.erasePersonalData).toHaveBeenCalledWith(userId, null, transactionClient);
+      expect(notificationsRepository.replaceTextForCounterparties).toHaveBeenCalledWith(
+        userId, 'alice', dataErasureService.ERASED_USERNAME, transactionClient
+      );
+    });
+
+    it('should run every step in one transaction and surface failures', async () => {
+      (credentialsRepository.deleteByUserId as jest.Mock).mockRejectedValueOnce(new Error('connection reset'));
+
+      await expect(dataErasureService.eraseUserData(userId)).rejects.toThrow('connection reset');
+      expect(withTransaction).toHaveBeenCalledTimes(1);
+      expect(reviewsRepository.clearCommentsByReviewer).toHaveBeenCalledWith(userId, transactionClient);
+    });
+
+    it('should reject unknown users and modes', async () => {
+      (usersRepository.findById as jest.Mock).mockResolvedValueOnce(null);
+
+      await expect(dataErasureService.eraseUserData(userId)).rejects.toThrow(NotFoundError);
+      await expect(dataErasureService.eraseUserData(userId, 'shred' as ErasureMode)).rejects.toThrow(BadRequestError);
+      expect(usersRepository.erasePersonalData).not.toHaveBeenCalled();
+    });
+  });
+
+  describe('pseudonymFor', () => {
+    it('should be stable and not reveal the user id', () => {
+      expect(dataErasureService.pseudonymFor(userId)).toBe(dataErasureService.pseudonymFor(userId));
+      expect(dataErasureService.pseudonymFor(userId)).not.toContain(userId);
+    });
+  });
+
+  describe('requestErasure', () => {
+    it('should erase data when the request is signed by the wallet', async () => {
+      const requestedAt = Date.now();
+
+      const summary = await dataErasureService.requestErasure(userId, walletAddress, requestedAt, sign(requestedAt));
+
+      expect(summary.userId).toBe(userId);
+      expect(usersRepository.erasePersonalData).toHaveBeenCalled();
+    });
+
+    it('should reject signatures from another wallet', async () => {
+      const requestedAt = Date.now();
+      const otherWallet = Keypair.generate().publicKey.toString();
+
+      await expect(dataErasureService.requestErasure(userId, otherWallet, requestedAt, sign(requestedAt)))
+        .rejects.toThrow(UnauthorizedError);
+      expect(usersRepository.erasePersonalData).not.toHaveBeenCalled();
+    });
+
+    it('should reject a signed request replayed with a different mode', async () => {
+      const requestedAt = Date.now();
+
+      await expect(dataErasureService.requestErasure(
+        userId, walletAddress, requestedAt, sign(requestedAt, ErasureMode.PSEUDONYMIZE), ErasureMode.DELETE
+      )).rejects.toThrow(UnauthorizedError);
+      expect(usersRepository.erasePersonalData).not.toHaveBeenCalled();
+    });
+
+    it('should reject expired requests', async () => {
+      const requestedAt = Date.now() - dataErasureService.ERASURE_REQUEST_MAX_AGE_MS - 1000;
+
+      await expect(dataErasureService.requestErasure(userId, walletAddress, requestedAt, sign(requestedAt)))
+        .rejects.toThrow('expired');
+    });
+  });
+});
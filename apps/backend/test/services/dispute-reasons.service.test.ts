@@ -0,0 +1,67 @@
+import * as disputeReasonsService from '../../src/services/dispute-reasons.service';
+import { DisputeReasonCode } from '../../src/types';
+
+describe('Dispute Reasons Service', () => {
+  describe('resolveLocale', () => {
+    it('should default to English when no locale is given', () => {
+      expect(disputeReasonsService.resolveLocale()).toBe('en');
+    });
+
+    it('should fall back to the base language for regional locales', () => {
+      expect(disputeReasonsService.resolveLocale('es-MX')).toBe('es');
+      expect(disputeReasonsService.resolveLocale('FR_ca')).toBe('fr');
+    });
+
+    it('should fall back to English for unsupported locales', () => {
+      expect(disputeReasonsService.resolveLocale('ja')).toBe('en');
+    });
+
+    it('should not resolve prototype keys or non-string values', () => {
+      expect(disputeReasonsService.resolveLocale('constructor')).toBe('en');
+      expect(disputeReasonsService.resolveLocale('toString')).toBe('en');
+      expect(disputeReasonsService.resolveLocale('__proto__')).toBe('en');
+      expect(disputeReasonsService.resolveLocale(['es', 'fr'])).toBe('en');
+      expect(disputeReasonsService.getDisputeReasons('constructor')[0].label).toBe('Item not received');
+    });
+  });
+
+  describe('getDisputeReasons', () => {
+    it('should return every reason code in every supported locale', () => {
+      const codes = Object.values(DisputeReasonCode);
+
+      for (const locale of disputeReasonsService.getSupportedLocales()) {
+        const reasons = disputeReasonsService.getDisputeReasons(locale);
+
+        expect(reasons.map((reason) => reason.code)).toEqual(codes);
+        reasons.forEach((reason) => {
+          expect(reason.label).toBeTruthy();
+          expect(reason.description).toBeTruthy();
+        });
+      }
+    });
+
+    it('should return localized labels', () => {
+      const reason = disputeReasonsService.getDisputeReason(DisputeReasonCode.ITEM_DAMAGED, 'es');
+
+      expect(reason.label).toBe('Artículo dañado');
+    });
+  });
+
+  describe('isDisputeReasonCode', () => {
+    it('should accept known codes and reject unknown ones', () => {
+      expect(disputeReasonsService.isDisputeReasonCode('item_not_received')).toBe(true);
+      expect(disputeReasonsService.isDisputeReasonCode('not_a_code')).toBe(false);
+    });
+  });
+
+  describe('buildDisputeReasonMetadata', () => {
+    it('should include the code and a label for each locale', () => {
+      const metadata = disputeReasonsService.buildDisputeReasonMetadata(DisputeReasonCode.OTHER);
+
+      expect(metadata).toEqual({
+        reasonCode: DisputeReasonCode.OTHER,
+        reasonLabels: { en: 'Other', es: 'Otro', fr: 'Autre' }
+      });
+    });
+  });
+});
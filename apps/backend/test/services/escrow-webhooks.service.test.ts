@@ -0,0 +1,193 @@
+import axios from 'axios';
+import * as escrowWebhooksService from '../../src/services/escrow-webhooks.service';
+import * as escrowStateChangesRepository from '../../src/db/escrow-state-changes.repository';
+import config from '../../src/config';
+import { EscrowStatus } from '../../src/types';
+import { NotFoundError } from '../../src/utils/errors';
+
+jest.mock('axios');
+jest.mock('../../src/db/escrow-state-changes.repository');
+jest.mock('../../src/config', () => ({
+  __esModule: true,
+  default: {
+    webhooks: {
+      urls: ['https://market.example/hooks/escrow', 'https://analytics.example/hooks/escrow'],
+      secret: 'test-secret',
+      pollIntervalMs: 1000,
+      maxAttempts: 3,
+      retentionDays: 7
+    }
+  }
+}));
+jest.mock('../../src/utils/logger', () => ({
+  info: jest.fn(),
+  error: jest.fn(),
+  warn: jest.fn(),
+  debug: jest.fn(),
+}));
+
+describe('Escrow Webhooks Service', () => {
+  const change = (id: string, escrowId: string, oldStatus: EscrowStatus | null, newStatus: EscrowStatus) => ({
+    id,
+    escrowId,
+    escrowAddress: 'escrow-address-123',
+    listingId: 'listing-123',
+    oldStatus,
+    newStatus,
+    amount: 100,
+    currency: 'USDC',
+    attempts: 0,
+    deliveredUrls: [] as string[],
+    lastError: null,
+    deliveredAt: null,
+    deadLetteredAt: null,
+    createdAt: new Date('2025-01-01T00:00:00Z')
+  });
+
+  beforeEach(() => {
+    jest.clearAllMocks();
+    (axios.post as jest.Mock).mockResolvedValue({ status: 200 });
+  });
+
+  describe('signPayload', () => {
+    it('should produce signatures that verify only for the same body and timestamp', () => {
+      const body = JSON.stringify({ hello: 'world' });
+      const signature = escrowWebhooksService.signPayload(body, 1700000000, 'test-secret');
+
+      expect(signature).toMatch(/^sha256=[0-9a-f]{64}$/);
+      expect(escrowWebhooksService.verifySignature(body, 1700000000, signature, 'test-secret')).toBe(true);
+      expect(escrowWebhooksService.verifySignature(body, 1700000001, signature, 'test-secret')).toBe(false);
+      expect(escrowWebhooksService.verifySignature(`${body} `, 1700000000, signature, 'test-secret')).toBe(false);
+      expect(escrowWebhooksService.verifySignature(body, 1700000000, signature, 'other-secret')).toBe(false);
+    });
+  });
+
+  describe('dispatchPendingStateChanges', () => {
+    it('should POST a signed payload and mark the change delivered', async () => {
+      (escrowStateChangesRepository.claimPending as jest.Mock).mockResolvedValue([
+        change('1', 'escrow-1', EscrowStatus.CREATED, EscrowStatus.FUNDED)
+      ]);
+
+      const delivered = await escrowWebhooksService.dispatchPendingStateChanges();
+
+      expect(delivered).toBe(1);
+      expect(axios.post).toHaveBeenCalledTimes(2);
+      const [url, body, options] = (axios.post as jest.Mock).mock.calls[0];
+      expect(url).toBe('https://market.example/hooks/escrow');
+      expect(JSON.parse(body)).toEqual({
+        id: '1',
+        type: 'escrow.state_changed',
+        escrowId: 'escrow-1',
+        escrowAddress: 'escrow-address-123',
+        listingId: 'listing-123',
+        oldState: EscrowStatus.CREATED,
+        newState: EscrowStatus.FUNDED,
+        amount: 100,
+        currency: 'USDC',
+        occurredAt: '2025-01-01T00:00:00.000Z'
+      });
+
+      const timestamp = Number(options.headers[escrowWebhooksService.TIMESTAMP_HEADER]);
+      const signature = options.headers[escrowWebhooksService.SIGNATURE_HEADER];
+      expect(escrowWebhooksService.verifySignature(body, timestamp, signature, 'test-secret')).toBe(true);
+      expect(escrowStateChangesRepository.markDelivered).toHaveBeenCalledWith('1', [
+        'https://market.example/hooks/escrow',
+        'https://analytics.example/hooks/escrow'
+      ]);
+    });
+
+    it('should remember endpoints that accepted a change and only retry the others', async () => {
+      (escrowStateChangesRepository.claimPending as jest.Mock).mockResolvedValue([
+        change('1', 'escrow-1', EscrowStatus.CREATED, EscrowStatus.FUNDED),
+        change('3', 'escrow-2', EscrowStatus.FUNDED, EscrowStatus.RELEASED)
+      ]);
+      (axios.post as jest.Mock).mockImplementation(async (url: string, body: string) => {
+        if (url.startsWith('https://analytics') && JSON.parse(body).id === '1') {
+          throw new Error('connect ECONNREFUSED');
+        }
+        return { status: 200 };
+      });
+
+      const delivered = await escrowWebhooksService.dispatchPendingStateChanges();
+
+      expect(delivered).toBe(1);
+      expect(escrowStateChangesRepository.recordFailedAttempt).toHaveBeenCalledWith(
+        '1',
+        expect.stringContaining('ECONNREFUSED'),
+        ['https://market.example/hooks/escrow'],
+        false
+      );
+      expect(escrowStateChangesRepository.markDelivered).toHaveBeenCalledWith('3', expect.any(Array));
+
+      jest.clearAllMocks();
+      (axios.post as jest.Mock).mockResolvedValue({ status: 200 });
+      (escrowStateChangesRepository.claimPending as jest.Mock).mockResolvedValue([
+        { ...change('1', 'escrow-1', EscrowStatus.CREATED, EscrowStatus.FUNDED), attempts: 1, deliveredUrls: ['https://market.example/hooks/escrow'] }
+      ]);
+
+      await escrowWebhooksService.dispatchPendingStateChanges();
+
+      expect(axios.post).toHaveBeenCalledTimes(1);
+      expect((axios.post as jest.Mock).mock.calls[0][0]).toBe('https://analytics.example/hooks/escrow');
+      expect(escrowStateChangesRepository.markDelivered).toHaveBeenCalledWith('1', [
+        'https://market.example/hooks/escrow',
+        'https://analytics.example/hooks/escrow'
+      ]);
+    });
+
+    it('should dead-letter a change on its last attempt', async () => {
+      (escrowStateChangesRepository.claimPending as jest.Mock).mockResolvedValue([
+        { ...change('1', 'escrow-1', EscrowStatus.CREATED, EscrowStatus.FUNDED), attempts: 2 }
+      ]);
+      (axios.post as jest.Mock).mockRejectedValueOnce(new Error('503 Service Unavailable'));
+
+      await escrowWebhooksService.dispatchPendingStateChanges();
+
+      expect(escrowStateChangesRepository.recordFailedAttempt).toHaveBeenCalledWith('1', expect.any(String), expect.any(Array), true);
+    });
+  });
+
+  describe('dead letters', () => {
+    it('should throw NotFoundError when requeueing or discarding an unknown dead letter', async () => {
+      (escrowStateChangesRepository.requeueDeadLetter as jest.Mock).mockResolvedValue(null);
+      (escrowStateChangesRepository.deleteDeadLetter as jest.Mock).mockResolvedValue(false);
+
+      await expect(escrowWebhooksService.requeueDeadLetter('42')).rejects.toThrow(NotFoundError);
+      await expect(escrowWebhooksService.discardDeadLetter('42')).rejects.toThrow(NotFoundError);
+    });
+  });
+
+  describe('pruneDeliveredStateChanges', () => {
+    it('should prune delivered changes past the retention period', async () => {
+      (escrowStateChangesRepository.pruneDelivered as jest.Mock).mockResolvedValue(12);
+
+      await expect(escrowWebhooksService.pruneDeliveredStateChanges()).resolves.toBe(12);
+      expect(escrowStateChangesRepository.pruneDelivered).toHaveBeenCalledWith(7);
+    });
+  });
+
+  describe('startEscrowWebhookDaemon', () => {
+    afterEach(() => {
+      escrowWebhooksService.stopEscrowWebhookDaemon();
+    });
+
+    it('should enable outbox recording when endpoints and a secret are configured', async () => {
+      await escrowWebhooksService.startEscrowWebhookDaemon();
+
+      expect(escrowStateChangesRepository.enableRecording).toHaveBeenCalled();
+    });
+
+    it('should leave outbox recording alone when webhooks are not configured', async () => {
+      const urls = config.webhooks.urls;
+      config.webhooks.urls = [];
+
+      try {
+        await escrowWebhooksService.startEscrowWebhookDaemon();
+      } finally {
+        config.webhooks.urls = urls;
+      }
+
+      expect(escrowStateChangesRepository.enableRecording).not.toHaveBeenCalled();
+    });
+  });
+});
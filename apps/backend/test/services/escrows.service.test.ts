@@ -22,6 +22,13 @@ jest.mock('../../src/blockchain/escrow', () => {
   };
 });
 
+const mockPreflightFunding = jest.fn();
+jest.mock('../../src/blockchain/escrow.service', () => ({
+  ...jest.requireActual('../../src/blockchain/escrow.service'),
+  __esModule: true,
+  default: { preflightFunding: (...args: any[]) => mockPreflightFunding(...args) }
+}));
+
 // Import modules after all mock declarations
 import * as escrowsService from '../../src/services/escrows.service';
 import * as escrowsRepository from '../../src/db/escrows.repository';
@@ -31,6 +38,7 @@ import * as notificationsService from '../../src/services/notifications.service'
 import { NotFoundError, BadRequestError, ForbiddenError } from '../../src/utils/errors';
 import { EscrowStatus, ListingStatus } from '../../src/types';
 import { EscrowService } from '../../src/blockchain/escrow';
+import { FundingPrerequisite } from '../../src/blockchain/escrow.service';
 
 // Get the mock blockchain service instance
 const mockEscrowServiceInstance = new EscrowService();
@@ -734,4 +742,63 @@ describe('Escrows Service', () => {
       expect(mockRefundEscrow).not.toHaveBeenCalled();
     });
   });
+  
+  describe('preflightEscrowFunding', () => {
+    const buyerId = 'buyer-123';
+    const mockListing = {
+      id: 'listing-123',
+      title: 'Test Listing',
+      price: 100,
+      currency: 'USDC',
+      sellerId: 'seller-123',
+      status: ListingStatus.ACTIVE
+    };
+    const onChainCheck = {
+      prerequisite: FundingPrerequisite.SELLER_TOKEN_ACCOUNT,
+      satisfied: true,
+      message: 'Seller has a USDC token account'
+    };
+    
+    beforeEach(() => {
+      (usersRepository.findById as jest.Mock).mockImplementation(async (id: string) => ({
+        id,
+        walletAddress: `${id}-wallet`
+      }));
+      mockPreflightFunding.mockResolvedValue({ ready: true, checks: [onChainCheck], unmet: [] });
+    });
+    
+    it('should be ready when the listing can be bought and the on-chain checks pass', async () => {
+      (listingsRepository.findById as jest.Mock).mockResolvedValue(mockListing);
+      
+      const result = await escrowsService.preflightEscrowFunding(buyerId, 'listing-123');
+      
+      expect(result.ready).toBe(true);
+      expect(result.checks.map((check) => check.prerequisite)).toEqual([
+        FundingPrerequisite.LISTING_AVAILABLE,
+        FundingPrerequisite.BUYER_IS_NOT_SELLER,
+        FundingPrerequisite.SELLER_TOKEN_ACCOUNT
+      ]);
+      expect(mockPreflightFunding).toHaveBeenCalledWith(
+        'buyer-123-wallet', 'seller-123-wallet', 100, 'USDC', 'listing-123'
+      );
+    });
+    
+    it('should report an unavailable listing', async () => {
+      (listingsRepository.findById as jest.Mock).mockResolvedValue({ ...mockListing, status: ListingStatus.SOLD });
+      
+      const result = await escrowsService.preflightEscrowFunding(buyerId, 'listing-123');
+      
+      expect(result.ready).toBe(false);
+      expect(result.unmet.map((check) => check.prerequisite)).toEqual([FundingPrerequisite.LISTING_AVAILABLE]);
+    });
+    
+    it('should report a buyer funding their own listing', async () => {
+      (listingsRepository.findById as jest.Mock).mockResolvedValue({ ...mockListing, sellerId: buyerId });
+      
+      const result = await escrowsService.preflightEscrowFunding(buyerId, 'listing-123');
+      
+      expect(result.ready).toBe(false);
+      expect(result.unmet.map((check) => check.prerequisite)).toEqual([FundingPrerequisite.BUYER_IS_NOT_SELLER]);
+    });
+  });
 });
@@ -0,0 +1,137 @@
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { getAssociatedTokenAddress } from '@solana/spl-token';
+import * as onboardingService from '../../src/services/onboarding.service';
+import * as listingsRepository from '../../src/db/listings.repository';
+import * as usersRepository from '../../src/db/users.repository';
+import * as notificationsService from '../../src/services/notifications.service';
+import escrowService from '../../src/blockchain/escrow.service';
+import { solanaPayService } from '../../src/services/solana-pay.service';
+import { BadRequestError, NotFoundError } from '../../src/utils/errors';
+
+jest.mock('../../src/db/listings.repository');
+jest.mock('../../src/db/users.repository');
+jest.mock('../../src/services/notifications.service');
+jest.mock('../../src/services/transaction-monitor.service', () => ({
+  __esModule: true,
+  default: { addTransactionToMonitor: jest.fn() }
+}));
+jest.mock('../../src/services/stablecoin.service', () => ({
+  __esModule: true,
+  StablecoinType: { USDC: 'USDC', USDT: 'USDT', PAX: 'PAX' },
+  default: { getMintAddress: jest.fn() }
+}));
+jest.mock('../../src/services/solana-pay.service', () => ({
+  solanaPayService: {
+    isSupportedCurrency: jest.fn((currency: string) => ['USDC', 'SOL'].includes(currency)),
+    createPaymentRequest: jest.fn()
+  }
+}));
+jest.mock('../../src/utils/logger', () => ({
+  info: jest.fn(),
+  error: jest.fn(),
+  warn: jest.fn(),
+  debug: jest.fn(),
+}));
+
+describe('Onboarding Service', () => {
+  const sellerId = 'seller-123';
+  const listingId = '123e4567-e89b-12d3-a456-426614174000';
+  const sellerWallet = Keypair.generate().publicKey.toString();
+  const buyerWallet = Keypair.generate().publicKey.toString();
+
+  beforeEach(() => {
+    jest.clearAllMocks();
+
+    (usersRepository.findById as jest.Mock).mockResolvedValue({
+      id: sellerId,
+      walletAddress: sellerWallet
+    });
+    (listingsRepository.create as jest.Mock).mockImplementation(async (data) => ({
+      ...data,
+      id: listingId
+    }));
+    (listingsRepository.deleteById as jest.Mock).mockResolvedValue(true);
+  });
+
+  describe('importListingsFromCsv', () => {
+    it('should create listings and derive escrow addresses for rows with a buyer', async () => {
+      const csv = [
+        'title,price,currency,description,buyer_wallet',
+        `Desk lamp,25,USDC,"Brass, barely used",${buyerWallet}`,
+        'Chair,40,SOL,,'
+      ].join('\n');
+
+      const result = await onboardingService.importListingsFromCsv(sellerId, csv);
+
+      expect(result.failed).toEqual([]);
+      expect(result.imported).toHaveLength(2);
+      expect(listingsRepository.create).toHaveBeenCalledWith(
+        expect.objectContaining({ title: 'Desk lamp', price: 25, description: 'Brass, barely used' })
+      );
+
+      const escrow = result.imported[0].escrow!;
+      const vault = await getAssociatedTokenAddress(
+        new PublicKey(escrowService.getTokenMintAddress('USDC')),
+        new PublicKey(escrow.escrowAddress),
+        true
+      );
+      expect(escrow).toEqual({
+        buyerWalletAddress: buyerWallet,
+        escrowAddress: expect.any(String),
+        vaultAddress: vault.toString(),
+        fundingLink: `lumepay:fund/${escrow.escrowAddress}?amount=25&currency=USDC&listing=${listingId}`
+      });
+      expect(result.imported[1].escrow).toBeUndefined();
+      expect(notificationsService.createListingNotification).toHaveBeenCalledTimes(1);
+    });
+
+    it('should derive the same escrow address as the escrow creation path', async () => {
+      const csv = ['title,price,currency,buyer_wallet', `Desk lamp,25,USDC,${buyerWallet}`].join('\n');
+
+      const result = await onboardingService.importListingsFromCsv(sellerId, csv);
+      const created = await escrowService.createEscrow(sellerWallet, buyerWallet, 25, listingId, 'USDC');
+
+      expect(result.imported[0].escrow!.escrowAddress).toBe(created.escrowAddress);
+      expect(solanaPayService.createPaymentRequest).not.toHaveBeenCalled();
+    });
+
+    it('should report invalid rows without creating listings for them', async () => {
+      const csv = [
+        'title,price,currency,buyer_wallet',
+        'No price,,USDC,',
+        'Bad buyer,10,USDC,invalid',
+        `Native escrow,10,SOL,${buyerWallet}`,
+        'Good,10,USDC,'
+      ].join('\r\n');
+
+      const result = await onboardingService.importListingsFromCsv(sellerId, csv);
+
+      expect(result.imported.map((listing) => listing.row)).toEqual([5]);
+      expect(result.failed.map((failure) => failure.row)).toEqual([2, 3, 4]);
+      expect(listingsRepository.create).toHaveBeenCalledTimes(1);
+    });
+
+    it('should remove the listing when the escrow addresses cannot be derived', async () => {
+      jest.spyOn(escrowService, 'findEscrowPDA').mockRejectedValueOnce(new Error('Invalid seeds'));
+      const csv = ['title,price,currency,buyer_wallet', `Desk lamp,25,USDC,${buyerWallet}`].join('\n');
+
+      const result = await onboardingService.importListingsFromCsv(sellerId, csv);
+
+      expect(result.imported).toEqual([]);
+      expect(result.failed).toEqual([{ row: 2, error: 'Invalid seeds' }]);
+      expect(listingsRepository.deleteById).toHaveBeenCalledWith(listingId);
+    });
+
+    it('should throw NotFoundError if the seller does not exist', async () => {
+      (usersRepository.findById as jest.Mock).mockResolvedValue(null);
+
+      await expect(onboardingService.importListingsFromCsv(sellerId, 'title,price,currency\nA,1,USDC'))
+        .rejects.toThrow(NotFoundError);
+    });
+
+    it('should throw BadRequestError for an empty CSV', async () => {
+      await expect(onboardingService.importListingsFromCsv(sellerId, 'title,price,currency\n'))
+        .rejects.toThrow(BadRequestError);
+    });
+  });
+});
@@ -0,0 +1,86 @@
+import { buildDeepLink, parseDeepLink, DeepLinkAction, DeepLinkError } from '../../src/utils/deep-link';
+import { DisputeReasonCode } from '../../src/types';
+import { ESCROW_CURRENCIES, TOKEN_MINT_ADDRESSES } from '../../src/blockchain/escrow-mints';
+
+describe('Deep links', () => {
+  const escrowAddress = '9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin';
+
+  describe('buildDeepLink', () => {
+    it('should build a fund link with amount and currency', () => {
+      const uri = buildDeepLink({
+        action: DeepLinkAction.FUND,
+        escrowAddress,
+        amount: 25.5,
+        currency: 'USDC'
+      });
+
+      expect(uri).toBe(`lumepay:fund/${escrowAddress}?amount=25.5&currency=USDC`);
+    });
+
+    it('should build a view link without parameters', () => {
+      expect(buildDeepLink({ action: DeepLinkAction.VIEW, escrowAddress })).toBe(`lumepay:view/${escrowAddress}`);
+    });
+
+    it('should reject fund links without an amount', () => {
+      expect(() => buildDeepLink({ action: DeepLinkAction.FUND, escrowAddress, currency: 'USDC' }))
+        .toThrow(DeepLinkError);
+    });
+  });
+
+  describe('parseDeepLink', () => {
+    it('should round-trip a dispute link', () => {
+      const link = {
+        action: DeepLinkAction.DISPUTE,
+        escrowAddress,
+        reasonCode: DisputeReasonCode.ITEM_DAMAGED,
+        listingId: 'listing-123'
+      };
+
+      expect(parseDeepLink(buildDeepLink(link))).toEqual({
+        ...link,
+        amount: undefined,
+        currency: undefined
+      });
+    });
+
+    it('should parse a fund link', () => {
+      const link = parseDeepLink(`lumepay:fund/${escrowAddress}?amount=10&currency=USDT`);
+
+      expect(link.action).toBe(DeepLinkAction.FUND);
+      expect(link.amount).toBe(10);
+      expect(link.currency).toBe('USDT');
+    });
+
+    it('should reject other schemes', () => {
+      expect(() => parseDeepLink(`solana:${escrowAddress}`)).toThrow(DeepLinkError);
+    });
+
+    it('should reject unknown actions and malformed paths', () => {
+      expect(() => parseDeepLink(`lumepay:withdraw/${escrowAddress}`)).toThrow('unknown action');
+      expect(() => parseDeepLink('lumepay:fund')).toThrow(DeepLinkError);
+      expect(() => parseDeepLink(`lumepay:view/${escrowAddress}/extra`)).toThrow(DeepLinkError);
+    });
+
+    it('should reject invalid escrow addresses', () => {
+      expect(() => parseDeepLink('lumepay:view/not-a-pubkey')).toThrow('not a valid escrow address');
+    });
+
+    it('should reject parameters that do not apply to the action', () => {
+      expect(() => parseDeepLink(`lumepay:confirm/${escrowAddress}?amount=5`)).toThrow(DeepLinkError);
+      expect(() => parseDeepLink(`lumepay:view/${escrowAddress}?reason=other`)).toThrow(DeepLinkError);
+    });
+
+    it('should accept exactly the currencies the escrow has mints for', () => {
+      expect(ESCROW_CURRENCIES).toEqual(Object.keys(TOKEN_MINT_ADDRESSES.devnet));
+      ESCROW_CURRENCIES.forEach((currency) => {
+        expect(parseDeepLink(`lumepay:fund/${escrowAddress}?amount=1&currency=${currency}`).currency).toBe(currency);
+      });
+    });
+
+    it('should reject unsupported amounts, currencies and reasons', () => {
+      expect(() => parseDeepLink(`lumepay:fund/${escrowAddress}?amount=-1&currency=USDC`)).toThrow(DeepLinkError);
+      expect(() => parseDeepLink(`lumepay:fund/${escrowAddress}?amount=1&currency=DOGE`)).toThrow(DeepLinkError);
+      expect(() => parseDeepLink(`lumepay:dispute/${escrowAddress}?reason=bored`)).toThrow(DeepLinkError);
+    });
+  });
+});